@@ -0,0 +1,68 @@
+//! An immutable, contiguous snapshot of a [`List`].
+
+use crate::storage::Storage;
+use crate::{List, Node};
+use alloc::boxed::Box;
+use core::ops::Index;
+
+/// The items of a [`List`] packed into one allocation in list order, for
+/// read-heavy use once building is done. Created by [`List::freeze`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FrozenList<T> {
+    items: Box<[T]>,
+}
+
+impl<T, S: Storage<Node<T>>> List<T, S> {
+    /// Moves the items into a [`FrozenList`], front to back.
+    pub fn freeze(self) -> FrozenList<T> {
+        FrozenList {
+            items: self.into_vec().into_boxed_slice(),
+        }
+    }
+}
+
+impl<T> FrozenList<T> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the item `index` places from the front, in O(1).
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Moves the items back into a mutable [`List`]. The ids it issues are
+    /// unrelated to those of the list that was frozen.
+    pub fn thaw(self) -> List<T> {
+        self.items.into_vec().into()
+    }
+}
+
+impl<T> Index<usize> for FrozenList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FrozenList<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -1,14 +1,56 @@
-use slab::*;
-use std::ops::{Deref, DerefMut};
+#![no_std]
 
-#[derive(Copy, Clone)]
-pub struct NodeId(usize);
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+pub mod frozen;
+#[macro_use]
+mod link;
+#[cfg(feature = "lru")]
+pub mod lru;
+#[cfg(feature = "rayon")]
+mod rayon_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod singly;
+pub mod storage;
+
+use link::Link;
+use storage::{SlabStorage, Storage};
+
+/// A handle to a node in a [`List`].
+///
+/// Ids carry the generation of the slab slot they were issued for, so an id
+/// whose node has been removed does not resolve to a node inserted later
+/// into the same slot. Generations are `u32` and wrap around, so this only
+/// holds until that one slot has been reused 2^32 times; after that, a
+/// stale id can resolve again.
+///
+/// On 64-bit targets a `NodeId` is 16 bytes. Nodes link to each other by
+/// `NodeId`, so generations cost 16 bytes per node, plus 4 bytes per slot
+/// for the generation table: a `Node<u64>` is 56 bytes, where bare `usize`
+/// links would make it 40.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
 
 #[derive(Copy, Clone)]
 pub struct Node<T> {
     item: T,
-    next: Option<usize>,
-    prev: Option<usize>,
+    next: Option<NodeId>,
+    prev: Option<NodeId>,
 }
 
 impl<T> Deref for Node<T> {
@@ -24,33 +66,231 @@ impl<T> DerefMut for Node<T> {
     }
 }
 
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Node")
+                .field("item", &self.item)
+                .field("next", &self.next)
+                .field("prev", &self.prev)
+                .finish()
+        } else {
+            f.debug_tuple("Node").field(&self.item).finish()
+        }
+    }
+}
+
 impl<T> Node<T> {
     pub fn into_inner(self) -> T {
         self.item
     }
 
     pub fn next(&self) -> Option<NodeId> {
-        Some(NodeId(self.next?))
+        self.next
     }
 
     pub fn prev(&self) -> Option<NodeId> {
-        Some(NodeId(self.prev?))
+        self.prev
+    }
+}
+
+impl<T> Link<T> for Node<T> {
+    fn next_link(&self) -> Option<NodeId> {
+        self.next
+    }
+
+    fn into_item(self) -> T {
+        self.item
+    }
+}
+
+/// The error returned by the checked structural operations such as
+/// [`List::try_remove`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ListError {
+    /// The id does not refer to a node in the list.
+    NotFound,
+    /// The node's links do not agree with its neighbours'.
+    Corrupted,
+    /// The end of a range does not follow its start.
+    InvalidRange,
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListError::NotFound => f.write_str("node not found in list"),
+            ListError::Corrupted => f.write_str("node links are inconsistent"),
+            ListError::InvalidRange => f.write_str("range end does not follow its start"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ListError {}
+
+/// The first structural inconsistency found by [`List::validate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Corruption {
+    /// The list's front link does not refer to a node.
+    DanglingInit,
+    /// The given node's `next` link does not refer to a node.
+    DanglingNext(NodeId),
+    /// The given node's `prev` link does not point at its predecessor.
+    BrokenPrev(NodeId),
+    /// Following `next` links visits more nodes than the list holds.
+    Cycle,
+    /// The walk from the front does not end at the list's back link.
+    WrongLast,
+    /// The given node is stored in the list but unreachable from the front.
+    Orphan(NodeId),
+}
+
+impl fmt::Display for Corruption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Corruption::DanglingInit => f.write_str("front link is dangling"),
+            Corruption::DanglingNext(id) => write!(f, "next link of {:?} is dangling", id),
+            Corruption::BrokenPrev(id) => write!(f, "prev link of {:?} is broken", id),
+            Corruption::Cycle => f.write_str("next links form a cycle"),
+            Corruption::WrongLast => f.write_str("walk does not end at the back link"),
+            Corruption::Orphan(id) => write!(f, "{:?} is unreachable from the front", id),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Corruption {}
+
+/// The error returned by [`List::try_reserve`] when the requested capacity
+/// cannot be allocated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryReserveError {
+    _private: (),
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("requested capacity exceeds the maximum allocation size")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {}
+
+/// A shared reference to a node, together with the id it was looked up by.
+#[derive(Copy, Clone)]
+pub struct NodeRef<'a, T> {
+    id: NodeId,
+    node: &'a Node<T>,
+}
+
+impl<'a, T> NodeRef<'a, T> {
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn node(self) -> &'a Node<T> {
+        self.node
+    }
+}
+
+impl<'a, T> Deref for NodeRef<'a, T> {
+    type Target = Node<T>;
+    fn deref(&self) -> &Node<T> {
+        self.node
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for NodeRef<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.node.fmt(f)
+    }
+}
+
+/// A mutable reference to a node, together with the id it was looked up by.
+pub struct NodeMut<'a, T> {
+    id: NodeId,
+    node: &'a mut Node<T>,
+}
+
+impl<'a, T> NodeMut<'a, T> {
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn into_mut(self) -> &'a mut Node<T> {
+        self.node
+    }
+}
+
+impl<'a, T> Deref for NodeMut<'a, T> {
+    type Target = Node<T>;
+    fn deref(&self) -> &Node<T> {
+        self.node
+    }
+}
+
+impl<'a, T> DerefMut for NodeMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Node<T> {
+        self.node
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for NodeMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.node.fmt(f)
     }
 }
 
 #[derive(Clone)]
-pub struct List<T> {
-    inner: Slab<Node<T>>,
-    init: Option<usize>,
-    last: Option<usize>,
+pub struct List<T, S = SlabStorage<Node<T>>> {
+    inner: S,
+    init: Option<NodeId>,
+    last: Option<NodeId>,
+    marker: PhantomData<T>,
 }
 
-impl<T> Default for List<T> {
+/// A [`List`] that stores up to `N` nodes inline before spilling to the heap.
+#[cfg(feature = "smallvec")]
+pub type InlineList<T, const N: usize> = List<T, storage::InlineStorage<Node<T>, N>>;
+
+impl<T, S: Storage<Node<T>>> Default for List<T, S> {
     fn default() -> Self {
         List {
-            inner: Slab::new(),
+            inner: S::with_capacity(0),
             init: None,
             last: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, S: Storage<Node<T>>> PartialEq for List<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, S: Storage<Node<T>>> Eq for List<T, S> {}
+
+impl<T: PartialOrd, S: Storage<Node<T>>> PartialOrd for List<T, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, S: Storage<Node<T>>> Ord for List<T, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash, S: Storage<Node<T>>> Hash for List<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for item in self {
+            item.hash(state);
         }
     }
 }
@@ -61,18 +301,151 @@ impl<T> List<T> {
     }
 
     pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_storage(cap)
+    }
+
+    pub fn builder() -> ListBuilder<T> {
+        ListBuilder::new()
+    }
+}
+
+impl<T, S: Storage<Node<T>>> List<T, S> {
+    /// Creates an empty list backed by `S`. Use this instead of
+    /// [`List::new`] when the storage type is not the default.
+    pub fn with_storage() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity_and_storage(cap: usize) -> Self {
         List {
-            inner: Slab::with_capacity(cap),
+            inner: S::with_capacity(cap),
             ..Self::default()
         }
     }
 
-    pub fn get(&self, id: NodeId) -> Option<&Node<T>> {
-        self.inner.get(id.0)
+    fn vacant_id(&self) -> NodeId {
+        let (index, generation) = self.inner.vacant();
+        NodeId { index, generation }
+    }
+
+    fn node(&self, id: NodeId) -> &Node<T> {
+        debug_assert!(self.contains(id));
+        unsafe { self.inner.get_unchecked(id.index) }
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut Node<T> {
+        debug_assert!(self.contains(id));
+        unsafe { self.inner.get_unchecked_mut(id.index) }
+    }
+
+    /// Returns the id of the node `index` places from the front.
+    ///
+    /// This walks the list from whichever end is closer, so it is O(n).
+    pub fn nth(&self, index: usize) -> Option<NodeId> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut cursor;
+        if index <= self.len() / 2 {
+            cursor = self.cursor_front();
+            for _ in 0..index {
+                cursor.try_next();
+            }
+        } else {
+            cursor = self.cursor_back();
+            for _ in index + 1..self.len() {
+                cursor.try_prev();
+            }
+        }
+        cursor.position()
+    }
+
+    /// Returns the id of the node `index` places from the back. Like
+    /// [`List::nth`], this is O(n).
+    pub fn nth_from_back(&self, index: usize) -> Option<NodeId> {
+        if index >= self.len() {
+            return None;
+        }
+        self.nth(self.len() - 1 - index)
+    }
+
+    fn set_front(&mut self, head: NodeId) {
+        let (init, last) = match (self.init, self.last) {
+            (Some(init), Some(last)) if init != head => (init, last),
+            _ => return,
+        };
+        self.node_mut(last).next = Some(init);
+        self.node_mut(init).prev = Some(last);
+        let new_last = self.node_mut(head).prev.take().unwrap();
+        self.node_mut(new_last).next = None;
+        self.init = Some(head);
+        self.last = Some(new_last);
+    }
+
+    fn get_node(&self, id: NodeId) -> Option<&Node<T>> {
+        if !self.contains(id) {
+            return None;
+        }
+        self.inner.get(id.index)
+    }
+
+    fn get_node_mut(&mut self, id: NodeId) -> Option<&mut Node<T>> {
+        if !self.contains(id) {
+            return None;
+        }
+        self.inner.get_mut(id.index)
+    }
+
+    pub fn get(&self, id: NodeId) -> Option<NodeRef<'_, T>> {
+        let node = self.get_node(id)?;
+        Some(NodeRef { id, node })
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> Option<NodeMut<'_, T>> {
+        let node = self.get_node_mut(id)?;
+        Some(NodeMut { id, node })
+    }
+
+    /// Replaces the item at `id`, returning the old one, or `None` if `id` is
+    /// not in the list.
+    pub fn replace(&mut self, id: NodeId, item: T) -> Option<T> {
+        let node = self.get_node_mut(id)?;
+        Some(core::mem::replace(&mut node.item, item))
+    }
+
+    /// Returns mutable references to several nodes at once, or `None` if any
+    /// id is absent or appears more than once.
+    pub fn get_many_mut<const N: usize>(&mut self, ids: [NodeId; N]) -> Option<[&mut Node<T>; N]> {
+        if !ids.iter().all(|&id| self.contains(id)) {
+            return None;
+        }
+        self.inner.get_disjoint_mut(ids.map(|id| id.index))
+    }
+
+    /// Returns mutable references to two distinct nodes, or `None` if either
+    /// id is absent or `a == b`.
+    pub fn get2_mut(&mut self, a: NodeId, b: NodeId) -> Option<(&mut Node<T>, &mut Node<T>)> {
+        let [a, b] = self.get_many_mut([a, b])?;
+        Some((a, b))
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
     }
 
-    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut Node<T>> {
-        self.inner.get_mut(id.0)
+    pub fn is_empty(&self) -> bool {
+        debug_assert_eq!(self.init.is_none(), self.inner.is_empty());
+        self.init.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.init = None;
+        self.last = None;
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
     }
 
     pub fn reserve(&mut self, additional: usize) {
@@ -83,180 +456,2003 @@ impl<T> List<T> {
         self.inner.reserve_exact(additional);
     }
 
+    fn check_capacity(&self, additional: usize) -> Result<(), TryReserveError> {
+        let per_node = core::mem::size_of::<Node<T>>() + core::mem::size_of::<u32>();
+        self.len()
+            .checked_add(additional)
+            .and_then(|cap| cap.checked_mul(per_node))
+            .filter(|&bytes| bytes <= isize::MAX as usize)
+            .map(|_| ())
+            .ok_or(TryReserveError { _private: () })
+    }
+
+    /// Like [`List::reserve`], but returns an error instead of panicking when
+    /// the requested capacity overflows.
+    ///
+    /// The backing slab has no fallible allocation API, so this only guards
+    /// against sizes that can never be allocated; an allocator failure for a
+    /// representable size still aborts.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.check_capacity(additional)?;
+        self.reserve(additional);
+        Ok(())
+    }
+
+    /// Like [`List::reserve_exact`], with the same caveats as
+    /// [`List::try_reserve`].
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.check_capacity(additional)?;
+        self.reserve_exact(additional);
+        Ok(())
+    }
+
+    /// Releases unused capacity at the end of the backing slab. Occupied
+    /// entries are never relocated, so existing ids remain valid.
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit();
+    }
+
+    /// Reduces capacity to at least `min_capacity`, and never below the
+    /// current length. Does nothing if capacity is already that small.
+    ///
+    /// The slab cannot shrink past its last occupied entry, so this compacts
+    /// the list first: like [`List::compact`], it invalidates every id
+    /// previously issued by the list.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let target = min_capacity.max(self.len());
+        if self.capacity() <= target {
+            return;
+        }
+        self.compact();
+        self.reserve_exact(target - self.len());
+    }
+
+    /// Returns `true` if the nodes occupy slab slots `0..len` in list order,
+    /// as after [`List::compact`] or a run of `push_back`s into an empty list.
+    ///
+    /// The slab stores each node inside a tagged entry, so even a contiguous
+    /// list cannot be borrowed as `&[Node<T>]`; use [`List::freeze`] for a
+    /// packed slice of the items.
+    pub fn is_contiguous(&self) -> bool {
+        self.iter_ids().enumerate().all(|(i, id)| id.index == i)
+    }
+
+    /// Moves every node into a fresh, densely packed slab in list order,
+    /// returning `(old, new)` id pairs in front-to-back order.
+    ///
+    /// This invalidates every id previously issued by the list.
+    pub fn compact(&mut self) -> Vec<(NodeId, NodeId)> {
+        let mut items = Vec::with_capacity(self.len());
+        while let Some(id) = self.init {
+            items.push((id, self.unlink(id).item));
+        }
+        // clearing resets the free list, so the pushes below fill it densely
+        self.inner.clear();
+        let mapping = items
+            .into_iter()
+            .map(|(old, item)| (old, self.push_back(item)))
+            .collect();
+        self.inner.shrink_to_fit();
+        mapping
+    }
+
+    /// Clones the list into a fresh, densely packed slab in list order.
+    ///
+    /// The derived `Clone` copies the slab verbatim, holes included, so every
+    /// id stays valid in the copy. Ids issued by this list must not be used
+    /// with the result of `clone_compact`.
+    pub fn clone_compact(&self) -> List<T, S>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
     pub fn push_front(&mut self, item: T) -> NodeId {
-        let vacant = self.inner.vacant_entry();
-        let key = vacant.key();
-        let next = self.init.replace(key);
-        vacant.insert(Node {
+        let id = self.vacant_id();
+        let next = self.init.replace(id);
+        let key = self.inner.insert(Node {
             item,
             next,
             prev: None,
         });
-        if let Some(ix) = next {
-            debug_assert!(self.inner.contains(ix));
-            let next = unsafe { self.inner.get_unchecked_mut(ix) };
-            let old_prev = next.prev.replace(key);
+        debug_assert_eq!(key, id.index);
+        if let Some(next_id) = next {
+            let old_prev = self.node_mut(next_id).prev.replace(id);
             debug_assert_eq!(old_prev, None);
+        } else {
+            self.last = Some(id);
+        }
+        id
+    }
+
+    /// Like [`List::push_front`], but also returns a reference to the newly
+    /// inserted item.
+    pub fn push_front_mut(&mut self, item: T) -> (NodeId, &mut T) {
+        let id = self.push_front(item);
+        (id, &mut self.node_mut(id).item)
+    }
+
+    /// Like [`List::push_back`], but also returns a reference to the newly
+    /// inserted item.
+    pub fn push_back_mut(&mut self, item: T) -> (NodeId, &mut T) {
+        let id = self.push_back(item);
+        (id, &mut self.node_mut(id).item)
+    }
+
+    /// Pushes `item` to the front only if that can be done without growing
+    /// the backing storage, handing it back otherwise.
+    pub fn push_front_within_capacity(&mut self, item: T) -> Result<NodeId, T> {
+        if self.inner.len() < self.inner.capacity() {
+            Ok(self.push_front(item))
+        } else {
+            Err(item)
+        }
+    }
+
+    /// Pushes `item` to the back only if that can be done without growing
+    /// the backing storage, handing it back otherwise.
+    pub fn push_back_within_capacity(&mut self, item: T) -> Result<NodeId, T> {
+        if self.inner.len() < self.inner.capacity() {
+            Ok(self.push_back(item))
+        } else {
+            Err(item)
         }
-        NodeId(key)
     }
 
     pub fn push_back(&mut self, item: T) -> NodeId {
-        let vacant = self.inner.vacant_entry();
-        let key = vacant.key();
-        let prev = self.last.replace(key);
-        vacant.insert(Node {
+        let id = self.vacant_id();
+        let prev = self.last.replace(id);
+        let key = self.inner.insert(Node {
             item,
             prev,
             next: None,
         });
-        if let Some(ix) = prev {
-            debug_assert!(self.inner.contains(ix));
-            let prev = unsafe { self.inner.get_unchecked_mut(ix) };
-            let old_next = prev.next.replace(key);
+        debug_assert_eq!(key, id.index);
+        if let Some(prev_id) = prev {
+            let old_next = self.node_mut(prev_id).next.replace(id);
             debug_assert_eq!(old_next, None);
+        } else {
+            self.init = Some(id);
         }
-        NodeId(key)
+        id
     }
 
-    pub fn remove(&mut self, id: NodeId) -> Option<Node<T>> {
-        if !self.inner.contains(id.0) {
+    /// Inserts `item` immediately after `id`, returning `None` if `id` is not
+    /// in the list.
+    pub fn insert_after(&mut self, id: NodeId, item: T) -> Option<NodeId> {
+        if !self.contains(id) {
             return None;
         }
+        Some(self.link_after(id, item))
+    }
 
-        let node = self.inner.remove(id.0);
+    /// Inserts `item` immediately before `id`, returning `None` if `id` is not
+    /// in the list.
+    pub fn insert_before(&mut self, id: NodeId, item: T) -> Option<NodeId> {
+        if !self.contains(id) {
+            return None;
+        }
+        Some(self.link_before(id, item))
+    }
 
-        if let Some(prev_ix) = node.prev {
-            debug_assert!(self.inner.contains(prev_ix));
-            let prev = unsafe { self.inner.get_unchecked_mut(prev_ix) };
-            debug_assert_eq!(prev.next, Some(id.0));
-            prev.next = node.next;
+    fn link_after(&mut self, id: NodeId, item: T) -> NodeId {
+        let new_id = self.vacant_id();
+        let next = self.node_mut(id).next.replace(new_id);
+        let key = self.inner.insert(Node {
+            item,
+            next,
+            prev: Some(id),
+        });
+        debug_assert_eq!(key, new_id.index);
+        if let Some(next_id) = next {
+            let old_prev = self.node_mut(next_id).prev.replace(new_id);
+            debug_assert_eq!(old_prev, Some(id));
         } else {
-            self.init = node.next;
+            self.last = Some(new_id);
         }
+        new_id
+    }
 
-        if let Some(next_ix) = node.next {
-            debug_assert!(self.inner.contains(next_ix));
-            let next = unsafe { self.inner.get_unchecked_mut(next_ix) };
-            debug_assert_eq!(next.prev, Some(id.0));
-            next.prev = node.prev;
+    fn link_before(&mut self, id: NodeId, item: T) -> NodeId {
+        let new_id = self.vacant_id();
+        let prev = self.node_mut(id).prev.replace(new_id);
+        let key = self.inner.insert(Node {
+            item,
+            prev,
+            next: Some(id),
+        });
+        debug_assert_eq!(key, new_id.index);
+        if let Some(prev_id) = prev {
+            let old_next = self.node_mut(prev_id).next.replace(new_id);
+            debug_assert_eq!(old_next, Some(id));
         } else {
-            self.last = node.prev;
+            self.init = Some(new_id);
         }
-
-        Some(node)
+        new_id
     }
 
-    pub fn init(&self) -> Option<NodeId> {
-        Some(NodeId(self.init?))
+    fn check_links(&self, id: NodeId) -> Result<(), ListError> {
+        if !self.contains(id) {
+            return Err(ListError::NotFound);
+        }
+        let node = self.node(id);
+        let prev_ok = match node.prev {
+            Some(prev) => self
+                .get_node(prev)
+                .is_some_and(|prev| prev.next == Some(id)),
+            None => self.init == Some(id),
+        };
+        let next_ok = match node.next {
+            Some(next) => self
+                .get_node(next)
+                .is_some_and(|next| next.prev == Some(id)),
+            None => self.last == Some(id),
+        };
+        if prev_ok && next_ok {
+            Ok(())
+        } else {
+            Err(ListError::Corrupted)
+        }
     }
 
-    pub fn last(&self) -> Option<NodeId> {
-        Some(NodeId(self.last?))
+    /// Like [`List::insert_after`], but also verifies the links around `id`
+    /// before touching them.
+    pub fn try_insert_after(&mut self, id: NodeId, item: T) -> Result<NodeId, ListError> {
+        self.check_links(id)?;
+        Ok(self.link_after(id, item))
     }
 
-    pub fn pop_front(&mut self) -> Option<Node<T>> {
-        self.remove(self.init()?)
+    /// Like [`List::insert_before`], but also verifies the links around `id`
+    /// before touching them.
+    pub fn try_insert_before(&mut self, id: NodeId, item: T) -> Result<NodeId, ListError> {
+        self.check_links(id)?;
+        Ok(self.link_before(id, item))
     }
 
-    pub fn pop_back(&mut self) -> Option<Node<T>> {
-        self.remove(self.last()?)
+    /// Like [`List::remove`], but also verifies the links around `id` before
+    /// touching them.
+    pub fn try_remove(&mut self, id: NodeId) -> Result<Node<T>, ListError> {
+        self.check_links(id)?;
+        Ok(self.unlink(id))
     }
 
-    pub fn contains(&self, id: NodeId) -> bool {
-        self.inner.contains(id.0)
+    /// Walks the whole list checking that every link is consistent and that
+    /// every stored node is reachable from the front.
+    pub fn validate(&self) -> Result<(), Corruption> {
+        let mut prev = None;
+        let mut current = self.init;
+        let mut steps = 0;
+        while let Some(id) = current {
+            let node = match self.get_node(id) {
+                Some(node) => node,
+                None => return Err(prev.map_or(Corruption::DanglingInit, Corruption::DanglingNext)),
+            };
+            if node.prev != prev {
+                return Err(Corruption::BrokenPrev(id));
+            }
+            steps += 1;
+            if steps > self.len() {
+                return Err(Corruption::Cycle);
+            }
+            prev = Some(id);
+            current = node.next;
+        }
+        if prev != self.last {
+            return Err(Corruption::WrongLast);
+        }
+        if steps < self.len() {
+            let mut reached = alloc::vec![false; self.inner.slots()];
+            for id in self.iter_ids() {
+                reached[id.index] = true;
+            }
+            let orphan = (0..self.inner.slots())
+                .filter(|&index| !reached[index])
+                .find_map(|index| {
+                    let generation = self.inner.generation(index)?;
+                    Some(NodeId { index, generation })
+                })
+                .unwrap();
+            return Err(Corruption::Orphan(orphan));
+        }
+        Ok(())
     }
 
-    pub fn cursor_front(&self) -> Cursor<T> {
-        Cursor {
-            current: self.init,
-            backing: self,
+    pub fn remove(&mut self, id: NodeId) -> Option<Node<T>> {
+        if !self.contains(id) {
+            return None;
         }
+        Some(self.unlink(id))
     }
 
-    pub fn cursor_back(&self) -> Cursor<T> {
-        Cursor {
-            current: self.last,
-            backing: self,
-        }
+    /// Like [`List::remove`], but also returns the ids that were before and
+    /// after the node, as `(prev, node, next)`. Removal never moves other
+    /// nodes, so there is no need for a `Vec`-style `swap_remove`.
+    pub fn remove_get_neighbors(
+        &mut self,
+        id: NodeId,
+    ) -> Option<(Option<NodeId>, Node<T>, Option<NodeId>)> {
+        let node = self.remove(id)?;
+        let (prev, next) = (node.prev, node.next);
+        Some((prev, node, next))
     }
 
-    pub fn cursor_at(&self, id: NodeId) -> Cursor<T> {
-        Cursor {
-            current: Some(id.0),
-            backing: self,
+    /// Removes every id in `ids` that is in the list, in any order, and
+    /// returns how many nodes were removed.
+    pub fn remove_all(&mut self, ids: &[NodeId]) -> usize {
+        let mut removed = 0;
+        for &id in ids {
+            if self.contains(id) {
+                self.unlink(id);
+                removed += 1;
+            }
         }
+        removed
     }
 
-    pub fn cursor_front_mut(&mut self) -> CursorMut<T> {
-        CursorMut {
-            current: self.init,
-            backing: self,
+    fn unlink(&mut self, id: NodeId) -> Node<T> {
+        let node = self.inner.remove(id.index);
+
+        if let Some(prev_id) = node.prev {
+            let prev = self.node_mut(prev_id);
+            debug_assert_eq!(prev.next, Some(id));
+            prev.next = node.next;
+        } else {
+            self.init = node.next;
         }
-    }
 
-    pub fn cursor_back_mut(&mut self) -> CursorMut<T> {
-        CursorMut {
-            current: self.last,
-            backing: self,
+        if let Some(next_id) = node.next {
+            let next = self.node_mut(next_id);
+            debug_assert_eq!(next.prev, Some(id));
+            next.prev = node.prev;
+        } else {
+            self.last = node.prev;
         }
+
+        node
     }
 
-    pub fn cursor_at_mut(&mut self, id: NodeId) -> CursorMut<T> {
-        CursorMut {
-            current: Some(id.0),
-            backing: self,
+    /// Looks up `id` once, returning a handle for operating on its node, or
+    /// `None` if `id` is not in the list.
+    pub fn entry(&mut self, id: NodeId) -> Option<Entry<'_, T, S>> {
+        if !self.contains(id) {
+            return None;
         }
+        Some(Entry { list: self, id })
     }
-}
 
-#[derive(Copy, Clone)]
-pub struct Cursor<'a, T> {
-    current: Option<usize>,
-    backing: &'a List<T>,
-}
+    fn detach(&mut self, id: NodeId) {
+        let node = self.node_mut(id);
+        let (prev, next) = (node.prev.take(), node.next.take());
+        match prev {
+            Some(prev_id) => self.node_mut(prev_id).next = next,
+            None => self.init = next,
+        }
+        match next {
+            Some(next_id) => self.node_mut(next_id).prev = prev,
+            None => self.last = prev,
+        }
+    }
 
-impl<'a, T> Cursor<'a, T> {
-    pub fn current(&self) -> Option<&'a Node<T>> {
-        self.backing.inner.get(self.current?)
+    fn attach_front(&mut self, id: NodeId) {
+        let next = self.init.replace(id);
+        self.node_mut(id).next = next;
+        match next {
+            Some(next_id) => self.node_mut(next_id).prev = Some(id),
+            None => self.last = Some(id),
+        }
     }
 
-    pub fn try_next(&mut self) -> bool {
-        if let Some(ix) = self.current().and_then(|n| n.next) {
-            self.current.replace(ix);
-            true
-        } else {
-            false
+    fn attach_back(&mut self, id: NodeId) {
+        let prev = self.last.replace(id);
+        self.node_mut(id).prev = prev;
+        match prev {
+            Some(prev_id) => self.node_mut(prev_id).next = Some(id),
+            None => self.init = Some(id),
         }
     }
 
-    pub fn try_prev(&mut self) -> bool {
-        if let Some(ix) = self.current().and_then(|n| n.prev) {
-            self.current.replace(ix);
-            true
-        } else {
-            false
+    /// Relinks `id` at the front of the list, keeping its id. Returns `false`
+    /// if `id` is not in the list.
+    pub fn move_to_front(&mut self, id: NodeId) -> bool {
+        if !self.contains(id) {
+            return false;
         }
+        if self.init != Some(id) {
+            self.detach(id);
+            self.attach_front(id);
+        }
+        true
     }
-}
 
-pub struct CursorMut<'a, T> {
-    current: Option<usize>,
-    backing: &'a mut List<T>,
-}
+    /// Relinks `id` at the back of the list, keeping its id. Returns `false`
+    /// if `id` is not in the list.
+    pub fn move_to_back(&mut self, id: NodeId) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        if self.last != Some(id) {
+            self.detach(id);
+            self.attach_back(id);
+        }
+        true
+    }
 
-impl<'a, T> CursorMut<'a, T> {
-    pub fn current(&mut self) -> Option<&mut Node<T>> {
-        self.backing.inner.get_mut(self.current?)
+    pub fn front_id(&self) -> Option<NodeId> {
+        self.init
     }
 
-    pub fn try_next(&mut self) -> bool {
-        if let Some(ix) = self.current().and_then(|n| n.next) {
-            self.current.replace(ix);
-            true
-        } else {
-            false
-        }
+    pub fn back_id(&self) -> Option<NodeId> {
+        self.last
+    }
+
+    #[deprecated(note = "renamed to `front_id`")]
+    pub fn init(&self) -> Option<NodeId> {
+        self.front_id()
+    }
+
+    #[deprecated(note = "renamed to `back_id`")]
+    pub fn last(&self) -> Option<NodeId> {
+        self.back_id()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        Some(&self.node(self.init?).item)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.node_mut(self.init?).item)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        Some(&self.node(self.last?).item)
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.node_mut(self.last?).item)
+    }
+
+    pub fn pop_front(&mut self) -> Option<Node<T>> {
+        self.remove(self.init?)
+    }
+
+    pub fn pop_back(&mut self) -> Option<Node<T>> {
+        self.remove(self.last?)
+    }
+
+    /// Grows or shrinks the list at the back to `new_len` items, filling new
+    /// nodes with clones of `value`.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(new_len, || value.clone());
+    }
+
+    /// Grows or shrinks the list at the back to `new_len` items, filling new
+    /// nodes by calling `f`.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len <= self.len() {
+            self.truncate_back(new_len);
+            return;
+        }
+        self.reserve(new_len - self.len());
+        while self.len() < new_len {
+            self.push_back(f());
+        }
+    }
+
+    /// Pops the front node only if `pred` accepts its item.
+    pub fn pop_front_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<Node<T>> {
+        let id = self.init?;
+        if pred(&self.node(id).item) {
+            Some(self.unlink(id))
+        } else {
+            None
+        }
+    }
+
+    /// Pops the back node only if `pred` accepts its item.
+    pub fn pop_back_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<Node<T>> {
+        let id = self.last?;
+        if pred(&self.node(id).item) {
+            Some(self.unlink(id))
+        } else {
+            None
+        }
+    }
+
+    /// Removes nodes from the back until at most `len` remain.
+    pub fn truncate_back(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop_back();
+        }
+    }
+
+    /// Removes nodes from the front until at most `len` remain.
+    pub fn truncate_front(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop_front();
+        }
+    }
+
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.inner.generation(id.index) == Some(id.generation)
+    }
+
+    /// Splits the list in two at `id`, returning a new list containing `id`
+    /// and every node after it, or `None` if `id` is not in the list.
+    ///
+    /// The moved nodes are reinserted into the new list's own slab, so ids
+    /// previously issued for them are not valid in either list afterwards.
+    pub fn split_off(&mut self, id: NodeId) -> Option<List<T, S>> {
+        if !self.contains(id) {
+            return None;
+        }
+        let mut tail = List::with_storage();
+        while let Some(last) = self.last {
+            let node = self.remove(last).unwrap();
+            tail.push_front(node.item);
+            if last == id {
+                break;
+            }
+        }
+        Some(tail)
+    }
+
+    /// Moves the last `count` nodes, or every node if there are fewer, into
+    /// a new list. As with [`List::split_off`], the moved nodes get fresh ids.
+    pub fn split_off_back(&mut self, count: usize) -> List<T, S> {
+        let count = count.min(self.len());
+        let mut tail = List::with_capacity_and_storage(count);
+        for _ in 0..count {
+            tail.push_front(self.pop_back().unwrap().item);
+        }
+        tail
+    }
+
+    /// Moves the first `count` nodes, or every node if there are fewer, into
+    /// a new list. As with [`List::split_off`], the moved nodes get fresh ids.
+    pub fn split_off_front(&mut self, count: usize) -> List<T, S> {
+        let count = count.min(self.len());
+        let mut head = List::with_capacity_and_storage(count);
+        for _ in 0..count {
+            head.push_back(self.pop_front().unwrap().item);
+        }
+        head
+    }
+
+    /// Detaches the nodes from `a` through `b` inclusive into a new list.
+    ///
+    /// Fails like [`List::drain_range`], leaving the list untouched. As with
+    /// [`List::split_off`], the moved nodes get fresh ids.
+    pub fn cut_range(&mut self, a: NodeId, b: NodeId) -> Result<List<T, S>, ListError> {
+        let mut range = List::with_storage();
+        range.extend(self.drain_range(a, b)?);
+        Ok(range)
+    }
+
+    /// Removes the nodes from `a` through `b` inclusive, yielding their items
+    /// in order. Nodes in the range not consumed before the iterator is
+    /// dropped are removed anyway.
+    ///
+    /// Fails with [`ListError::NotFound`] if either id is absent and with
+    /// [`ListError::InvalidRange`] if `b` does not follow `a`, leaving the
+    /// list untouched. Checking the range walks it from `a`.
+    pub fn drain_range(&mut self, a: NodeId, b: NodeId) -> Result<DrainRange<'_, T, S>, ListError> {
+        if !self.contains(a) || !self.contains(b) {
+            return Err(ListError::NotFound);
+        }
+        let mut current = a;
+        let mut remaining = 1;
+        while current != b {
+            current = self.node(current).next.ok_or(ListError::InvalidRange)?;
+            remaining += 1;
+        }
+        Ok(DrainRange {
+            list: self,
+            next: Some(a),
+            remaining,
+        })
+    }
+
+    /// Inserts the contents of `other` after `id`, in order. Fails with
+    /// [`ListError::NotFound`] if `id` is not in the list.
+    pub fn splice_range(&mut self, id: NodeId, other: List<T, S>) -> Result<(), ListError> {
+        if !self.contains(id) {
+            return Err(ListError::NotFound);
+        }
+        self.reserve(other.len());
+        let mut at = id;
+        for item in other {
+            at = self.link_after(at, item);
+        }
+        Ok(())
+    }
+
+    /// Moves every node of `other` to the back of this list, leaving `other`
+    /// empty.
+    ///
+    /// The moved nodes are reinserted into this list's slab, so ids issued by
+    /// `other` are not valid in this list.
+    pub fn append(&mut self, other: &mut List<T, S>) {
+        self.reserve(other.len());
+        while let Some(node) = other.pop_front() {
+            self.push_back(node.item);
+        }
+    }
+
+    /// Links `lists` end to end into one list, preserving order. The total
+    /// capacity is reserved up front.
+    pub fn concat<I: IntoIterator<Item = List<T, S>>>(lists: I) -> List<T, S> {
+        let lists: Vec<_> = lists.into_iter().collect();
+        let total = lists.iter().map(List::len).sum();
+        let mut list = List::with_capacity_and_storage(total);
+        for other in lists {
+            list.extend(other);
+        }
+        list
+    }
+
+    /// Swaps the items stored at `a` and `b`, leaving both ids attached to
+    /// their original positions. Returns `false` if either id is absent.
+    pub fn swap(&mut self, a: NodeId, b: NodeId) -> bool {
+        if a == b {
+            return self.contains(a);
+        }
+        match self.get_many_mut([a, b]) {
+            Some([x, y]) => {
+                core::mem::swap(&mut x.item, &mut y.item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn reverse(&mut self) {
+        let mut current = self.init;
+        while let Some(id) = current {
+            let node = self.node_mut(id);
+            core::mem::swap(&mut node.next, &mut node.prev);
+            current = node.prev;
+        }
+        core::mem::swap(&mut self.init, &mut self.last);
+    }
+
+    /// Reverses the nodes from `a` through `b` inclusive in place. Nodes are
+    /// relinked rather than moved, so every id stays attached to its item.
+    ///
+    /// Fails like [`List::drain_range`], leaving the list untouched.
+    pub fn reverse_range(&mut self, a: NodeId, b: NodeId) -> Result<(), ListError> {
+        if !self.contains(a) || !self.contains(b) {
+            return Err(ListError::NotFound);
+        }
+        let mut current = a;
+        while current != b {
+            current = self.node(current).next.ok_or(ListError::InvalidRange)?;
+        }
+        let before = self.node(a).prev;
+        let after = self.node(b).next;
+        let mut current = a;
+        loop {
+            let node = self.node_mut(current);
+            core::mem::swap(&mut node.next, &mut node.prev);
+            if current == b {
+                break;
+            }
+            current = node.prev.unwrap();
+        }
+        self.node_mut(b).prev = before;
+        self.node_mut(a).next = after;
+        match before {
+            Some(id) => self.node_mut(id).next = Some(b),
+            None => self.init = Some(b),
+        }
+        match after {
+            Some(id) => self.node_mut(id).prev = Some(a),
+            None => self.last = Some(a),
+        }
+        Ok(())
+    }
+
+    /// Rotates the list so that the node `n % len` places from the front
+    /// becomes the new front. Only the ends are relinked, so ids stay valid.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.is_empty() {
+            return;
+        }
+        if let Some(head) = self.nth(n % self.len()) {
+            self.set_front(head);
+        }
+    }
+
+    /// Rotates the list so that the node `n % len` places from the back
+    /// becomes the new back. Only the ends are relinked, so ids stay valid.
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.is_empty() {
+            return;
+        }
+        let k = n % self.len();
+        if k != 0 {
+            self.rotate_left(self.len() - k);
+        }
+    }
+
+    /// Rotates the list so that `id` becomes the front. Only the ends are
+    /// relinked, so ids stay valid. Returns `false` if `id` is not in the list.
+    pub fn rotate_to(&mut self, id: NodeId) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        self.set_front(id);
+        true
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|item| f(item));
+    }
+
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut current = self.init;
+        while let Some(id) = current {
+            let node = self.node_mut(id);
+            current = node.next;
+            if !f(&mut node.item) {
+                self.unlink(id);
+            }
+        }
+    }
+
+    /// Moves every item for which `pred` returns `false` into a new list,
+    /// keeping the rest. Both lists preserve the original relative order.
+    ///
+    /// Like [`List::split_off`], moved items get fresh ids in the new list.
+    pub fn partition<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> List<T, S> {
+        let mut rejected = List::with_storage();
+        let mut current = self.init;
+        while let Some(id) = current {
+            let node = self.node(id);
+            current = node.next;
+            if !pred(&node.item) {
+                rejected.push_back(self.unlink(id).item);
+            }
+        }
+        rejected
+    }
+
+    /// Removes every node whose id `keep` rejects, front to back.
+    pub fn retain_ids<F: FnMut(NodeId) -> bool>(&mut self, mut keep: F) {
+        let mut current = self.init;
+        while let Some(id) = current {
+            current = self.node(id).next;
+            if !keep(id) {
+                self.unlink(id);
+            }
+        }
+    }
+
+    /// Returns `true` if any item equals `value`. This is a linear scan; use
+    /// [`List::contains`] to check for an id.
+    pub fn contains_value(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == value)
+    }
+
+    pub fn count_if<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|item| pred(item)).count()
+    }
+
+    /// Returns `true` if `pred` accepts any item, stopping at the first
+    /// match. An empty list returns `false`.
+    pub fn any<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.iter().any(pred)
+    }
+
+    /// Returns `true` if `pred` accepts every item, stopping at the first
+    /// rejection. An empty list returns `true`.
+    pub fn all<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.iter().all(pred)
+    }
+
+    /// Accumulates over the items front to back.
+    ///
+    /// ```
+    /// use linked_slab::List;
+    ///
+    /// let l: List<i32> = (1..=4).collect();
+    /// assert_eq!(l.fold(0, |sum, x| sum + x), 10);
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Like [`List::fold`], but stops at the first error.
+    pub fn try_fold<B, E, F: FnMut(B, &T) -> Result<B, E>>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> Result<B, E> {
+        let mut acc = init;
+        for item in self {
+            acc = f(acc, item)?;
+        }
+        Ok(acc)
+    }
+
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<NodeId> {
+        let mut current = self.init;
+        while let Some(id) = current {
+            let node = self.node(id);
+            if pred(&node.item) {
+                return Some(id);
+            }
+            current = node.next;
+        }
+        None
+    }
+
+    pub fn find_mut<F: FnMut(&T) -> bool>(&mut self, pred: F) -> Option<&mut Node<T>> {
+        let id = self.find(pred)?;
+        self.get_node_mut(id)
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Stable bottom-up merge sort. Nodes are relinked rather than moved, so
+    /// every id stays attached to its item.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let mut head = self.init;
+        let mut width = 1;
+        loop {
+            let mut left = head;
+            let mut tail: Option<NodeId> = None;
+            let mut merges = 0;
+            head = None;
+            while left.is_some() {
+                merges += 1;
+                let mut right = left;
+                let mut left_len = 0;
+                while left_len < width {
+                    left_len += 1;
+                    right = self.node(right.unwrap()).next;
+                    if right.is_none() {
+                        break;
+                    }
+                }
+                let mut right_len = width;
+                loop {
+                    let right_done = right_len == 0 || right.is_none();
+                    let take_left = match (left_len, right_done) {
+                        (0, true) => break,
+                        (0, false) => false,
+                        (_, true) => true,
+                        _ => {
+                            let l = &self.node(left.unwrap()).item;
+                            let r = &self.node(right.unwrap()).item;
+                            compare(l, r) != Ordering::Greater
+                        }
+                    };
+                    let id = if take_left {
+                        let id = left.unwrap();
+                        left = self.node(id).next;
+                        left_len -= 1;
+                        id
+                    } else {
+                        let id = right.unwrap();
+                        right = self.node(id).next;
+                        right_len -= 1;
+                        id
+                    };
+                    match tail {
+                        Some(tail) => self.node_mut(tail).next = Some(id),
+                        None => head = Some(id),
+                    }
+                    tail = Some(id);
+                }
+                left = right;
+            }
+            if let Some(tail) = tail {
+                self.node_mut(tail).next = None;
+            }
+            if merges <= 1 {
+                break;
+            }
+            width *= 2;
+        }
+
+        let mut prev = None;
+        let mut current = head;
+        while let Some(id) = current {
+            let node = self.node_mut(id);
+            node.prev = prev;
+            prev = Some(id);
+            current = node.next;
+        }
+        self.init = head;
+        self.last = prev;
+    }
+
+    /// Merges the sorted list `other` into this sorted list, keeping the
+    /// result sorted. Runs in O(n + m); see [`List::merge_sorted_by`].
+    pub fn merge_sorted(&mut self, other: List<T, S>)
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, T::cmp);
+    }
+
+    /// Merges `other` into this list, both sorted by `compare`. The merge is
+    /// stable: on ties, items already in this list come first. Existing ids
+    /// stay valid; items from `other` are moved into this list's slab and
+    /// get fresh ids.
+    pub fn merge_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: List<T, S>,
+        mut compare: F,
+    ) {
+        self.reserve(other.len());
+        let mut current = self.init;
+        for item in other {
+            while let Some(id) = current {
+                if compare(&self.node(id).item, &item) == Ordering::Greater {
+                    break;
+                }
+                current = self.node(id).next;
+            }
+            match current {
+                Some(id) => {
+                    self.link_before(id, item);
+                }
+                None => {
+                    self.push_back(item);
+                }
+            }
+        }
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes every node for which `same_bucket(item, previous)` returns
+    /// `true`, where `previous` is the nearest retained node before it.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let mut kept = match self.init {
+            Some(id) => id,
+            None => return,
+        };
+        while let Some(id) = self.node(kept).next {
+            let [current, previous] = self.get_many_mut([id, kept]).unwrap();
+            if same_bucket(&mut current.item, &mut previous.item) {
+                self.remove(id);
+            } else {
+                kept = id;
+            }
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, S> {
+        Iter {
+            front: self.init,
+            back: self.last,
+            remaining: self.inner.len(),
+            storage: &self.inner,
+            marker: PhantomData,
+        }
+    }
+
+    /// Yields the items from `id` to the back, starting with `id` itself.
+    /// Yields nothing if `id` is not in the list.
+    pub fn iter_from(&self, id: NodeId) -> impl Iterator<Item = &T> {
+        self.cursor_at(id).items_forward()
+    }
+
+    /// Yields the items from `id` to the front, starting with `id` itself.
+    /// Yields nothing if `id` is not in the list.
+    pub fn iter_from_rev(&self, id: NodeId) -> impl Iterator<Item = &T> {
+        self.cursor_at(id).items_backward()
+    }
+
+    /// Walks this list and `other` from their fronts in lockstep, stopping
+    /// at the end of the shorter one.
+    pub fn zip<'a, U, R: Storage<Node<U>>>(
+        &'a self,
+        other: &'a List<U, R>,
+    ) -> core::iter::Zip<Iter<'a, T, S>, Iter<'a, U, R>> {
+        self.iter().zip(other.iter())
+    }
+
+    /// Removes every item from the list, yielding them front to back. Items
+    /// not consumed before the `Drain` is dropped are removed anyway.
+    pub fn drain(&mut self) -> Drain<'_, T, S> {
+        Drain { list: self }
+    }
+
+    /// Removes and yields, front to back, every item for which `pred` returns
+    /// `true`. Items not visited before the iterator is dropped are kept.
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F, S> {
+        ExtractIf {
+            next: self.init,
+            list: self,
+            pred,
+        }
+    }
+
+    /// Removes and yields items from the front for as long as `pred` returns
+    /// `true`. The first item that fails the predicate stays in the list, as
+    /// do any items not reached before the iterator is dropped.
+    pub fn drain_front_while<F: FnMut(&T) -> bool>(&mut self, pred: F) -> DrainWhile<'_, T, F, S> {
+        DrainWhile {
+            list: self,
+            pred,
+            back: false,
+            done: false,
+        }
+    }
+
+    /// Like [`List::drain_front_while`], but removes from the back.
+    pub fn drain_back_while<F: FnMut(&T) -> bool>(&mut self, pred: F) -> DrainWhile<'_, T, F, S> {
+        DrainWhile {
+            list: self,
+            pred,
+            back: true,
+            done: false,
+        }
+    }
+
+    /// Consumes the list, building a new one from `f` applied to each item in
+    /// order. The result uses the default storage.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> List<U> {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Like [`List::map`], but borrows the list.
+    pub fn map_ref<U, F: FnMut(&T) -> U>(&self, f: F) -> List<U> {
+        self.iter().map(f).collect()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.extend(self);
+        vec
+    }
+
+    pub fn into_vecdeque(self) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(self.len());
+        deque.extend(self);
+        deque
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.extend(self.iter().cloned());
+        vec
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, S> {
+        IterMut {
+            front: self.init,
+            back: self.last,
+            remaining: self.inner.len(),
+            raw: self.inner.raw(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Borrows the list as two disjoint mutable halves, like
+    /// `slice::split_at_mut`: the first holds every item before `id`, the
+    /// second `id` and every item after it. Returns `None` if `id` is not in
+    /// the list.
+    ///
+    /// The halves share no items, so they can be mutated independently (and
+    /// sent to different threads); the list's structure stays frozen while
+    /// either is alive. Building them walks the list up to `id` to find it,
+    /// then once more in full.
+    pub fn split_at_mut(&mut self, id: NodeId) -> Option<(ViewMut<'_, T>, ViewMut<'_, T>)> {
+        if !self.contains(id) {
+            return None;
+        }
+        let at = self.iter_ids().position(|x| x == id).unwrap();
+        let mut items = self.iter_mut();
+        let front = items.by_ref().take(at).collect();
+        let back = items.collect();
+        Some((ViewMut { items: front }, ViewMut { items: back }))
+    }
+
+    pub fn iter_ids(&self) -> Ids<'_, T, S> {
+        Ids {
+            front: self.init,
+            back: self.last,
+            remaining: self.inner.len(),
+            backing: self,
+        }
+    }
+
+    /// Yields the ids in list order in groups of `size`, the last group
+    /// possibly shorter. Each group is a freshly allocated `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunk_ids(&self, size: usize) -> ChunkIds<'_, T, S> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunkIds {
+            ids: self.iter_ids(),
+            size,
+        }
+    }
+
+    /// Yields every run of `size` consecutive ids in list order, advancing
+    /// by one node each step, like `slice::windows`. Yields nothing if the
+    /// list is shorter than `size`.
+    ///
+    /// The iterator keeps one internal buffer of `size` ids, but each window
+    /// is returned as a freshly allocated `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn window_ids(&self, size: usize) -> WindowIds<'_, T, S> {
+        assert!(size != 0, "window size must be non-zero");
+        WindowIds {
+            ids: self.iter_ids(),
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T, S> {
+        Cursor {
+            current: self.init,
+            backing: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T, S> {
+        Cursor {
+            current: self.last,
+            backing: self,
+        }
+    }
+
+    pub fn cursor_at(&self, id: NodeId) -> Cursor<'_, T, S> {
+        Cursor {
+            current: Some(id),
+            backing: self,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, S> {
+        CursorMut {
+            current: self.init,
+            backing: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, S> {
+        CursorMut {
+            current: self.last,
+            backing: self,
+        }
+    }
+
+    pub fn cursor_at_mut(&mut self, id: NodeId) -> CursorMut<'_, T, S> {
+        CursorMut {
+            current: Some(id),
+            backing: self,
+        }
+    }
+}
+
+impl_list_traits!(List, Node);
+
+impl<T, S: Storage<Node<T>>> From<List<T, S>> for Vec<T> {
+    fn from(list: List<T, S>) -> Vec<T> {
+        list.into_vec()
+    }
+}
+
+impl<T, S: Storage<Node<T>>> From<List<T, S>> for VecDeque<T> {
+    fn from(list: List<T, S>) -> VecDeque<T> {
+        list.into_vecdeque()
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> List<T> {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for List<T> {
+    fn from(deque: VecDeque<T>) -> List<T> {
+        deque.into_iter().collect()
+    }
+}
+
+/// Fluent construction of a [`List`].
+///
+/// ```
+/// use linked_slab::List;
+///
+/// let l = List::builder().push_back(2).push_back(3).push_front(1).build();
+/// assert!(l.iter().copied().eq(1..=3));
+/// ```
+pub struct ListBuilder<T, S = SlabStorage<Node<T>>> {
+    list: List<T, S>,
+}
+
+impl<T> ListBuilder<T> {
+    pub fn new() -> Self {
+        ListBuilder { list: List::new() }
+    }
+
+    pub fn with_capacity(cap: usize) -> Self {
+        ListBuilder {
+            list: List::with_capacity(cap),
+        }
+    }
+}
+
+impl<T> Default for ListBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S: Storage<Node<T>>> ListBuilder<T, S> {
+    pub fn push_back(mut self, item: T) -> Self {
+        self.list.push_back(item);
+        self
+    }
+
+    pub fn push_front(mut self, item: T) -> Self {
+        self.list.push_front(item);
+        self
+    }
+
+    pub fn build(self) -> List<T, S> {
+        self.list
+    }
+}
+
+/// Shared by both list types, with `N` the node type; only the doubly linked
+/// [`Node`] can be walked from the back.
+pub struct Iter<'a, T, S = SlabStorage<Node<T>>, N = Node<T>> {
+    pub(crate) front: Option<NodeId>,
+    pub(crate) back: Option<NodeId>,
+    pub(crate) remaining: usize,
+    pub(crate) storage: &'a S,
+    pub(crate) marker: PhantomData<&'a (T, N)>,
+}
+
+impl<'a, T, S, N> Clone for Iter<'a, T, S, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            front: self.front,
+            back: self.back,
+            remaining: self.remaining,
+            storage: self.storage,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, S: Storage<N>, N: Link<T> + 'a> Iterator for Iter<'a, T, S, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.storage.get(self.front?.index)?;
+        self.front = node.next_link();
+        self.remaining -= 1;
+        Some(node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> DoubleEndedIterator for Iter<'a, T, S> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.storage.get(self.back?.index)?;
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(&node.item)
+    }
+}
+
+impl<'a, T: 'a, S: Storage<N>, N: Link<T> + 'a> ExactSizeIterator for Iter<'a, T, S, N> {}
+
+impl<'a, T: 'a, S: Storage<N>, N: Link<T> + 'a> FusedIterator for Iter<'a, T, S, N> {}
+
+/// Shared by both list types, like [`Iter`].
+pub struct IterMut<'a, T, S: Storage<N> = SlabStorage<Node<T>>, N = Node<T>> {
+    pub(crate) front: Option<NodeId>,
+    pub(crate) back: Option<NodeId>,
+    pub(crate) remaining: usize,
+    pub(crate) raw: S::Raw,
+    pub(crate) marker: PhantomData<&'a mut (T, N)>,
+}
+
+impl<'a, T, S: Storage<N>, N: 'a> IterMut<'a, T, S, N> {
+    fn node(&mut self, id: NodeId) -> &'a mut N {
+        // Each id is yielded at most once: `remaining` stops the walk before
+        // the two ends can cross, so the returned borrows never alias. They
+        // are all derived from `raw`, which was taken once, so handing out
+        // one does not invalidate the others.
+        unsafe { &mut *S::raw_get(self.raw, id.index) }
+    }
+}
+
+// `raw` stands in for the `&'a mut` list the iterator was created from.
+unsafe impl<'a, T: Send, S: Storage<N> + Send, N: Send> Send for IterMut<'a, T, S, N> {}
+
+unsafe impl<'a, T: Sync, S: Storage<N> + Sync, N: Sync> Sync for IterMut<'a, T, S, N> {}
+
+impl<'a, T: 'a, S: Storage<N>, N: Link<T> + 'a> Iterator for IterMut<'a, T, S, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.node(self.front?);
+        self.front = node.next_link();
+        self.remaining -= 1;
+        Some(node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> DoubleEndedIterator for IterMut<'a, T, S> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.node(self.back?);
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(&mut node.item)
+    }
+}
+
+impl<'a, T: 'a, S: Storage<N>, N: Link<T> + 'a> ExactSizeIterator for IterMut<'a, T, S, N> {}
+
+impl<'a, T: 'a, S: Storage<N>, N: Link<T> + 'a> FusedIterator for IterMut<'a, T, S, N> {}
+
+pub struct Ids<'a, T, S = SlabStorage<Node<T>>> {
+    front: Option<NodeId>,
+    back: Option<NodeId>,
+    remaining: usize,
+    backing: &'a List<T, S>,
+}
+
+impl<'a, T, S> Clone for Ids<'a, T, S> {
+    fn clone(&self) -> Self {
+        Ids {
+            front: self.front,
+            back: self.back,
+            remaining: self.remaining,
+            backing: self.backing,
+        }
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> Iterator for Ids<'a, T, S> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let id = self.front?;
+        self.front = self.backing.node(id).next;
+        self.remaining -= 1;
+        Some(id)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> DoubleEndedIterator for Ids<'a, T, S> {
+    fn next_back(&mut self) -> Option<NodeId> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let id = self.back?;
+        self.back = self.backing.node(id).prev;
+        self.remaining -= 1;
+        Some(id)
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> ExactSizeIterator for Ids<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> FusedIterator for Ids<'a, T, S> {}
+
+/// One half of a list borrowed by [`List::split_at_mut`], in list order.
+pub struct ViewMut<'a, T> {
+    items: Vec<&'a mut T>,
+}
+
+impl<'a, T> ViewMut<'a, T> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + use<'_, 'a, T> {
+        self.items.iter().map(|item| &**item)
+    }
+
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator + use<'_, 'a, T> {
+        self.items.iter_mut().map(|item| &mut **item)
+    }
+}
+
+impl<'a, T> IntoIterator for ViewMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = alloc::vec::IntoIter<&'a mut T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+pub struct ChunkIds<'a, T, S = SlabStorage<Node<T>>> {
+    ids: Ids<'a, T, S>,
+    size: usize,
+}
+
+impl<'a, T, S> Clone for ChunkIds<'a, T, S> {
+    fn clone(&self) -> Self {
+        ChunkIds {
+            ids: self.ids.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> Iterator for ChunkIds<'a, T, S> {
+    type Item = Vec<NodeId>;
+
+    fn next(&mut self) -> Option<Vec<NodeId>> {
+        let chunk: Vec<NodeId> = self.ids.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.ids.len().div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> ExactSizeIterator for ChunkIds<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> FusedIterator for ChunkIds<'a, T, S> {}
+
+pub struct WindowIds<'a, T, S = SlabStorage<Node<T>>> {
+    ids: Ids<'a, T, S>,
+    window: VecDeque<NodeId>,
+    size: usize,
+}
+
+impl<'a, T, S> Clone for WindowIds<'a, T, S> {
+    fn clone(&self) -> Self {
+        WindowIds {
+            ids: self.ids.clone(),
+            window: self.window.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> Iterator for WindowIds<'a, T, S> {
+    type Item = Vec<NodeId>;
+
+    fn next(&mut self) -> Option<Vec<NodeId>> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            self.window.push_back(self.ids.next()?);
+        }
+        Some(self.window.iter().copied().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let windows = if self.window.is_empty() {
+            (self.ids.len() + 1).saturating_sub(self.size)
+        } else {
+            self.ids.len()
+        };
+        (windows, Some(windows))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> ExactSizeIterator for WindowIds<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> FusedIterator for WindowIds<'a, T, S> {}
+
+/// Shared by both list types, like [`Iter`]. Owns the storage and takes
+/// each node out of it in turn.
+#[derive(Clone)]
+pub struct IntoIter<T, S = SlabStorage<Node<T>>, N = Node<T>> {
+    pub(crate) inner: S,
+    pub(crate) front: Option<NodeId>,
+    pub(crate) back: Option<NodeId>,
+    pub(crate) remaining: usize,
+    pub(crate) marker: PhantomData<(T, N)>,
+}
+
+impl<T, S: Storage<N>, N: Link<T>> Iterator for IntoIter<T, S, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // neighbours are left pointing at removed nodes, but the walk never
+        // comes back to them
+        let node = self.inner.remove(self.front?.index);
+        self.front = node.next_link();
+        self.remaining -= 1;
+        Some(node.into_item())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, S: Storage<Node<T>>> DoubleEndedIterator for IntoIter<T, S> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.inner.remove(self.back?.index);
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(node.item)
+    }
+}
+
+impl<T, S: Storage<N>, N: Link<T>> ExactSizeIterator for IntoIter<T, S, N> {}
+
+impl<T, S: Storage<N>, N: Link<T>> FusedIterator for IntoIter<T, S, N> {}
+
+pub struct Drain<'a, T, S: Storage<Node<T>> = SlabStorage<Node<T>>> {
+    list: &'a mut List<T, S>,
+}
+
+impl<'a, T, S: Storage<Node<T>>> Iterator for Drain<'a, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front().map(Node::into_inner)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> DoubleEndedIterator for Drain<'a, T, S> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back().map(Node::into_inner)
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> ExactSizeIterator for Drain<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> FusedIterator for Drain<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> Drop for Drain<'a, T, S> {
+    fn drop(&mut self) {
+        self.list.clear();
+    }
+}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExtractIf<'a, T, F, S = SlabStorage<Node<T>>> {
+    list: &'a mut List<T, S>,
+    next: Option<NodeId>,
+    pred: F,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool, S: Storage<Node<T>>> Iterator for ExtractIf<'a, T, F, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(id) = self.next {
+            let node = self.list.node_mut(id);
+            self.next = node.next;
+            if (self.pred)(&mut node.item) {
+                return self.list.remove(id).map(Node::into_inner);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.list.len()))
+    }
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool, S: Storage<Node<T>>> FusedIterator
+    for ExtractIf<'a, T, F, S>
+{
+}
+
+pub struct DrainRange<'a, T, S: Storage<Node<T>> = SlabStorage<Node<T>>> {
+    list: &'a mut List<T, S>,
+    next: Option<NodeId>,
+    remaining: usize,
+}
+
+impl<'a, T, S: Storage<Node<T>>> Iterator for DrainRange<'a, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let id = self.next?;
+        self.remaining -= 1;
+        let node = self.list.unlink(id);
+        self.next = node.next.filter(|_| self.remaining != 0);
+        Some(node.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, S: Storage<Node<T>>> ExactSizeIterator for DrainRange<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> FusedIterator for DrainRange<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> Drop for DrainRange<'a, T, S> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DrainWhile<'a, T, F, S = SlabStorage<Node<T>>> {
+    list: &'a mut List<T, S>,
+    pred: F,
+    back: bool,
+    done: bool,
+}
+
+impl<'a, T, F: FnMut(&T) -> bool, S: Storage<Node<T>>> Iterator for DrainWhile<'a, T, F, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let end = if self.back {
+            self.list.last
+        } else {
+            self.list.init
+        };
+        match end {
+            Some(id) if (self.pred)(&self.list.node(id).item) => Some(self.list.unlink(id).item),
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, Some(self.list.len()))
+        }
+    }
+}
+
+impl<'a, T, F: FnMut(&T) -> bool, S: Storage<Node<T>>> FusedIterator for DrainWhile<'a, T, F, S> {}
+
+pub struct Entry<'a, T, S = SlabStorage<Node<T>>> {
+    list: &'a mut List<T, S>,
+    id: NodeId,
+}
+
+impl<'a, T, S: Storage<Node<T>>> Entry<'a, T, S> {
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn get(&self) -> &Node<T> {
+        self.list.node(self.id)
+    }
+
+    pub fn get_mut(&mut self) -> &mut Node<T> {
+        self.list.node_mut(self.id)
+    }
+
+    pub fn into_mut(self) -> &'a mut Node<T> {
+        self.list.node_mut(self.id)
+    }
+
+    pub fn remove(self) -> Node<T> {
+        self.list.unlink(self.id)
+    }
+
+    pub fn insert_after(&mut self, item: T) -> NodeId {
+        self.list.link_after(self.id, item)
+    }
+
+    pub fn insert_before(&mut self, item: T) -> NodeId {
+        self.list.link_before(self.id, item)
+    }
+}
+
+pub struct Cursor<'a, T, S = SlabStorage<Node<T>>> {
+    current: Option<NodeId>,
+    backing: &'a List<T, S>,
+}
+
+impl<'a, T, S> Clone for Cursor<'a, T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, S> Copy for Cursor<'a, T, S> {}
+
+impl<'a, T, S: Storage<Node<T>>> Cursor<'a, T, S> {
+    pub fn current(&self) -> Option<&'a Node<T>> {
+        self.backing.get_node(self.current?)
+    }
+
+    pub fn item(&self) -> Option<&'a T> {
+        Some(&self.current()?.item)
+    }
+
+    pub fn try_next(&mut self) -> bool {
+        if let Some(ix) = self.current().and_then(|n| n.next) {
+            self.current.replace(ix);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn try_prev(&mut self) -> bool {
+        if let Some(ix) = self.current().and_then(|n| n.prev) {
+            self.current.replace(ix);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves to the next node, wrapping from the back of the list to the
+    /// front. Returns `false` only if the list is empty.
+    pub fn move_next_cycle(&mut self) -> bool {
+        self.current = self.current().and_then(|n| n.next).or(self.backing.init);
+        self.current.is_some()
+    }
+
+    /// Moves to the previous node, wrapping from the front of the list to the
+    /// back. Returns `false` only if the list is empty.
+    pub fn move_prev_cycle(&mut self) -> bool {
+        self.current = self.current().and_then(|n| n.prev).or(self.backing.last);
+        self.current.is_some()
+    }
+
+    pub fn peek_next(&self) -> Option<&'a Node<T>> {
+        self.backing.get_node(self.current()?.next?)
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a Node<T>> {
+        self.backing.get_node(self.current()?.prev?)
+    }
+
+    pub fn position(&self) -> Option<NodeId> {
+        self.current
+    }
+
+    pub fn is_front(&self) -> bool {
+        self.current.is_some() && self.current == self.backing.init
+    }
+
+    pub fn is_back(&self) -> bool {
+        self.current.is_some() && self.current == self.backing.last
+    }
+
+    /// Returns `true` if the cursor is not positioned on a node in the list.
+    pub fn is_dangling(&self) -> bool {
+        !self.current.is_some_and(|id| self.backing.contains(id))
+    }
+
+    /// Yields the items from the current node to the back, starting with the
+    /// current one. Yields nothing if the cursor is dangling.
+    pub fn items_forward(self) -> impl Iterator<Item = &'a T> {
+        let backing = self.backing;
+        core::iter::successors(self.current(), move |node| backing.get_node(node.next?))
+            .map(|node| &node.item)
+    }
+
+    /// Yields the items from the current node to the front, starting with the
+    /// current one. Yields nothing if the cursor is dangling.
+    pub fn items_backward(self) -> impl Iterator<Item = &'a T> {
+        let backing = self.backing;
+        core::iter::successors(self.current(), move |node| backing.get_node(node.prev?))
+            .map(|node| &node.item)
+    }
+
+    /// Counts the nodes from the current one to the back, inclusive. This
+    /// walks the list, so it is O(n). Returns 0 if the cursor is dangling.
+    pub fn remaining_forward(&self) -> usize {
+        let mut cursor = *self;
+        if cursor.current().is_none() {
+            return 0;
+        }
+        1 + cursor.seek_forward(usize::MAX)
+    }
+
+    /// Counts the nodes from the current one to the front, inclusive. Like
+    /// [`Cursor::remaining_forward`], this is O(n).
+    pub fn remaining_backward(&self) -> usize {
+        let mut cursor = *self;
+        if cursor.current().is_none() {
+            return 0;
+        }
+        1 + cursor.seek_backward(usize::MAX)
+    }
+
+    /// Returns how many steps forward (positive) or backward (negative) `id`
+    /// is from the current node, or `None` if either is not in the list.
+    ///
+    /// Both directions are searched in lockstep, so this is O(d) in the
+    /// distance found, but O(n) in the worst case.
+    pub fn distance_to(&self, id: NodeId) -> Option<isize> {
+        let start = self.current.filter(|&c| self.backing.contains(c))?;
+        if !self.backing.contains(id) {
+            return None;
+        }
+        let (mut ahead, mut behind) = (Some(start), Some(start));
+        let mut steps = 0;
+        while ahead.is_some() || behind.is_some() {
+            if ahead == Some(id) {
+                return Some(steps);
+            }
+            if behind == Some(id) {
+                return Some(-steps);
+            }
+            ahead = ahead.and_then(|a| self.backing.node(a).next);
+            behind = behind.and_then(|b| self.backing.node(b).prev);
+            steps += 1;
+        }
+        None
+    }
+
+    /// Returns a new cursor over the same list positioned at `id`. If `id` is
+    /// not in the list (checked as by [`Cursor::goto`]), the new cursor's
+    /// position is `None`.
+    pub fn clone_at(&self, id: NodeId) -> Cursor<'a, T, S> {
+        Cursor {
+            current: Some(id).filter(|&id| self.backing.contains(id)),
+            backing: self.backing,
+        }
+    }
+
+    /// Advances up to `n` nodes, returning how many steps were taken.
+    pub fn seek_forward(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.try_next()).count()
+    }
+
+    /// Moves back up to `n` nodes, returning how many steps were taken.
+    pub fn seek_backward(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.try_prev()).count()
+    }
+
+    /// Advances to the next node whose item matches `pred`, skipping the
+    /// current one. If none matches, stops at the back and returns `false`.
+    pub fn find_forward<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        while self.try_next() {
+            if pred(&self.current().unwrap().item) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves back to the previous node whose item matches `pred`, skipping
+    /// the current one. If none matches, stops at the front and returns
+    /// `false`.
+    pub fn find_backward<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        while self.try_prev() {
+            if pred(&self.current().unwrap().item) {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn goto_front(&mut self) {
+        self.current = self.backing.init;
+    }
+
+    pub fn goto_back(&mut self) {
+        self.current = self.backing.last;
+    }
+
+    /// Moves to `id`, returning `false` and leaving the cursor in place if
+    /// `id` is not in the list.
+    pub fn goto(&mut self, id: NodeId) -> bool {
+        if !self.backing.contains(id) {
+            return false;
+        }
+        self.current = Some(id);
+        true
+    }
+}
+
+pub struct CursorMut<'a, T, S = SlabStorage<Node<T>>> {
+    current: Option<NodeId>,
+    backing: &'a mut List<T, S>,
+}
+
+impl<'a, T, S: Storage<Node<T>>> CursorMut<'a, T, S> {
+    pub fn current(&mut self) -> Option<&mut Node<T>> {
+        self.backing.get_node_mut(self.current?)
+    }
+
+    pub fn item_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.current()?.item)
+    }
+
+    pub fn try_next(&mut self) -> bool {
+        if let Some(ix) = self.current().and_then(|n| n.next) {
+            self.current.replace(ix);
+            true
+        } else {
+            false
+        }
     }
 
     pub fn try_prev(&mut self) -> bool {
@@ -267,4 +2463,207 @@ impl<'a, T> CursorMut<'a, T> {
             false
         }
     }
+
+    /// Inserts `item` after the current node. If the cursor is not on a node,
+    /// the item is pushed to the front of the list.
+    pub fn insert_after(&mut self, item: T) -> NodeId {
+        match self.current.filter(|&id| self.backing.contains(id)) {
+            Some(id) => self.backing.insert_after(id, item).unwrap(),
+            None => self.backing.push_front(item),
+        }
+    }
+
+    /// Inserts `item` before the current node. If the cursor is not on a node,
+    /// the item is pushed to the back of the list.
+    pub fn insert_before(&mut self, item: T) -> NodeId {
+        match self.current.filter(|&id| self.backing.contains(id)) {
+            Some(id) => self.backing.insert_before(id, item).unwrap(),
+            None => self.backing.push_back(item),
+        }
+    }
+
+    /// Removes the current node and moves the cursor to its successor, or to
+    /// its predecessor if it was the last node.
+    pub fn remove_current(&mut self) -> Option<Node<T>> {
+        let node = self.backing.remove(self.current?)?;
+        self.current = node.next.or(node.prev);
+        Some(node)
+    }
+
+    /// Removes nodes after the current one for as long as `pred` returns
+    /// `true`, returning how many were removed. The cursor does not move.
+    pub fn remove_next_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let mut removed = 0;
+        while let Some(next) = self.current().and_then(|n| n.next) {
+            if !pred(&self.backing.node(next).item) {
+                break;
+            }
+            self.backing.unlink(next);
+            removed += 1;
+        }
+        removed
+    }
+
+    /// Removes nodes before the current one for as long as `pred` returns
+    /// `true`, returning how many were removed. The cursor does not move.
+    pub fn remove_prev_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let mut removed = 0;
+        while let Some(prev) = self.current().and_then(|n| n.prev) {
+            if !pred(&self.backing.node(prev).item) {
+                break;
+            }
+            self.backing.unlink(prev);
+            removed += 1;
+        }
+        removed
+    }
+
+    /// Moves to the next node, wrapping from the back of the list to the
+    /// front. Returns `false` only if the list is empty.
+    pub fn move_next_cycle(&mut self) -> bool {
+        self.current = self.current().and_then(|n| n.next).or(self.backing.init);
+        self.current.is_some()
+    }
+
+    /// Moves to the previous node, wrapping from the front of the list to the
+    /// back. Returns `false` only if the list is empty.
+    pub fn move_prev_cycle(&mut self) -> bool {
+        self.current = self.current().and_then(|n| n.prev).or(self.backing.last);
+        self.current.is_some()
+    }
+
+    pub fn peek_next_mut(&mut self) -> Option<&mut Node<T>> {
+        let next = self.current()?.next?;
+        self.backing.get_node_mut(next)
+    }
+
+    pub fn peek_prev_mut(&mut self) -> Option<&mut Node<T>> {
+        let prev = self.current()?.prev?;
+        self.backing.get_node_mut(prev)
+    }
+
+    pub fn position(&self) -> Option<NodeId> {
+        self.current
+    }
+
+    /// Moves every node after the current one into a new list. If the cursor
+    /// is not on a node, the whole list is moved.
+    pub fn split_after(&mut self) -> List<T, S> {
+        let mut tail = List::with_storage();
+        while let Some(last) = self.backing.last {
+            if Some(last) == self.current {
+                break;
+            }
+            tail.push_front(self.backing.remove(last).unwrap().item);
+        }
+        tail
+    }
+
+    /// Moves every node before the current one into a new list. If the cursor
+    /// is not on a node, the whole list is moved.
+    pub fn split_before(&mut self) -> List<T, S> {
+        let mut head = List::with_storage();
+        while let Some(init) = self.backing.init {
+            if Some(init) == self.current {
+                break;
+            }
+            head.push_back(self.backing.remove(init).unwrap().item);
+        }
+        head
+    }
+
+    /// Inserts the contents of `other` after the current node, or at the
+    /// front of the list if the cursor is not on a node.
+    pub fn splice_after(&mut self, other: List<T, S>) {
+        self.backing.reserve(other.len());
+        for item in other.into_iter().rev() {
+            self.insert_after(item);
+        }
+    }
+
+    /// Inserts the contents of `other` before the current node, or at the
+    /// back of the list if the cursor is not on a node.
+    pub fn splice_before(&mut self, other: List<T, S>) {
+        self.backing.reserve(other.len());
+        for item in other {
+            self.insert_before(item);
+        }
+    }
+
+    pub fn is_front(&self) -> bool {
+        self.current.is_some() && self.current == self.backing.init
+    }
+
+    pub fn is_back(&self) -> bool {
+        self.current.is_some() && self.current == self.backing.last
+    }
+
+    /// Returns `true` if the cursor is not positioned on a node in the list.
+    pub fn is_dangling(&self) -> bool {
+        !self.current.is_some_and(|id| self.backing.contains(id))
+    }
+
+    /// Advances up to `n` nodes, returning how many steps were taken.
+    pub fn seek_forward(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.try_next()).count()
+    }
+
+    /// Moves back up to `n` nodes, returning how many steps were taken.
+    pub fn seek_backward(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.try_prev()).count()
+    }
+
+    /// Returns a read-only cursor at the same position.
+    pub fn as_cursor(&self) -> Cursor<'_, T, S> {
+        Cursor {
+            current: self.current,
+            backing: self.backing,
+        }
+    }
+
+    /// Gives up the borrow of the list, keeping only the current id.
+    pub fn into_position(self) -> Option<NodeId> {
+        self.current
+    }
+
+    /// Advances to the next node whose item matches `pred`, skipping the
+    /// current one. If none matches, stops at the back and returns `false`.
+    pub fn find_forward<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        while self.try_next() {
+            if pred(&self.current().unwrap().item) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves back to the previous node whose item matches `pred`, skipping
+    /// the current one. If none matches, stops at the front and returns
+    /// `false`.
+    pub fn find_backward<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        while self.try_prev() {
+            if pred(&self.current().unwrap().item) {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn goto_front(&mut self) {
+        self.current = self.backing.init;
+    }
+
+    pub fn goto_back(&mut self) {
+        self.current = self.backing.last;
+    }
+
+    /// Moves to `id`, returning `false` and leaving the cursor in place if
+    /// `id` is not in the list.
+    pub fn goto(&mut self, id: NodeId) -> bool {
+        if !self.backing.contains(id) {
+            return false;
+        }
+        self.current = Some(id);
+        true
+    }
 }
@@ -0,0 +1,115 @@
+//! What [`List`](crate::List) and [`SinglyList`](crate::singly::SinglyList)
+//! have in common.
+
+use crate::NodeId;
+use core::ops::DerefMut;
+
+/// A node that can be walked front to back. Implemented by both node types;
+/// the module is private, so the iterators that rely on `next_link` for
+/// soundness cannot be handed a node type from outside the crate.
+pub trait Link<T>: DerefMut<Target = T> {
+    fn next_link(&self) -> Option<NodeId>;
+
+    fn into_item(self) -> T;
+}
+
+/// Implements the traits both lists share for `$list<T, S>` over nodes of
+/// type `$node<T>`, in terms of `get_node`, `push_back`, `iter`, `iter_mut`
+/// and the crate-level iterators.
+macro_rules! impl_list_traits {
+    ($list:ident, $node:ident) => {
+        impl<T: core::fmt::Debug, S: $crate::storage::Storage<$node<T>>> core::fmt::Debug
+            for $list<T, S>
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T, S: $crate::storage::Storage<$node<T>>> core::ops::Index<$crate::NodeId>
+            for $list<T, S>
+        {
+            type Output = T;
+
+            fn index(&self, id: $crate::NodeId) -> &T {
+                match self.get_node(id) {
+                    Some(node) => &node.item,
+                    None => panic!("invalid or stale NodeId {:?}", id),
+                }
+            }
+        }
+
+        impl<T, S: $crate::storage::Storage<$node<T>>> core::ops::IndexMut<$crate::NodeId>
+            for $list<T, S>
+        {
+            fn index_mut(&mut self, id: $crate::NodeId) -> &mut T {
+                match self.get_node_mut(id) {
+                    Some(node) => &mut node.item,
+                    None => panic!("invalid or stale NodeId {:?}", id),
+                }
+            }
+        }
+
+        impl<T, S: $crate::storage::Storage<$node<T>>> core::iter::FromIterator<T> for $list<T, S> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let iter = iter.into_iter();
+                let mut list = $list::with_capacity_and_storage(iter.size_hint().0);
+                for item in iter {
+                    list.push_back(item);
+                }
+                list
+            }
+        }
+
+        impl<T, S: $crate::storage::Storage<$node<T>>> Extend<T> for $list<T, S> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                for item in iter {
+                    self.push_back(item);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a, S: $crate::storage::Storage<$node<T>>> Extend<&'a T>
+            for $list<T, S>
+        {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        impl<'a, T, S: $crate::storage::Storage<$node<T>>> IntoIterator for &'a $list<T, S> {
+            type Item = &'a T;
+            type IntoIter = $crate::Iter<'a, T, S, $node<T>>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, S: $crate::storage::Storage<$node<T>>> IntoIterator for &'a mut $list<T, S> {
+            type Item = &'a mut T;
+            type IntoIter = $crate::IterMut<'a, T, S, $node<T>>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        impl<T, S: $crate::storage::Storage<$node<T>>> IntoIterator for $list<T, S> {
+            type Item = T;
+            type IntoIter = $crate::IntoIter<T, S, $node<T>>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                $crate::IntoIter {
+                    front: self.init,
+                    back: self.last,
+                    remaining: self.inner.len(),
+                    inner: self.inner,
+                    marker: core::marker::PhantomData,
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,70 @@
+use crate::{List, NodeId};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A least-recently-used cache: a [`List`] keeps entries ordered from most
+/// to least recently used, and a map from keys to [`NodeId`]s finds them in
+/// O(1). Because ids survive [`List::move_to_front`], the map never needs
+/// updating on access.
+pub struct LruCache<K, V> {
+    list: List<(K, V)>,
+    map: HashMap<K, NodeId>,
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Creates a cache holding at most `capacity` entries. A cache with zero
+    /// capacity stores nothing.
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            list: List::with_capacity(capacity),
+            map: HashMap::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the value for `key`, marking it as most recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let id = *self.map.get(key)?;
+        self.list.move_to_front(id);
+        Some(&self.list[id].1)
+    }
+
+    /// Inserts `value` for `key`, marking it as most recently used and
+    /// returning the value it replaced. Inserting a new key into a full cache
+    /// evicts the least recently used entry.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&id) = self.map.get(&key) {
+            self.list.move_to_front(id);
+            return Some(core::mem::replace(&mut self.list[id].1, value));
+        }
+        if self.capacity == 0 {
+            return None;
+        }
+        if self.list.len() == self.capacity {
+            self.pop_lru();
+        }
+        let id = self.list.push_front((key.clone(), value));
+        self.map.insert(key, id);
+        None
+    }
+
+    /// Removes and returns the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_back()?.into_inner();
+        self.map.remove(&key);
+        Some((key, value))
+    }
+}
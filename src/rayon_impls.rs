@@ -0,0 +1,15 @@
+use crate::storage::Storage;
+use crate::{List, Node};
+use alloc::vec::Vec;
+use rayon::iter::IntoParallelIterator;
+
+/// Gathers references to the items into a `Vec` in list order, an O(n)
+/// sequential pass, and hands that buffer to rayon.
+impl<'a, T: Sync + 'a, S: Storage<Node<T>>> IntoParallelIterator for &'a List<T, S> {
+    type Item = &'a T;
+    type Iter = rayon::vec::IntoIter<&'a T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
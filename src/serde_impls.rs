@@ -0,0 +1,41 @@
+use crate::storage::Storage;
+use crate::{List, Node};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+impl<T: Serialize, S: Storage<Node<T>>> Serialize for List<T, S> {
+    fn serialize<R: Serializer>(&self, serializer: R) -> Result<R::Ok, R::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for item in self {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+struct ListVisitor<T, S>(PhantomData<(T, S)>);
+
+impl<'de, T: Deserialize<'de>, S: Storage<Node<T>>> Visitor<'de> for ListVisitor<T, S> {
+    type Value = List<T, S>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<List<T, S>, A::Error> {
+        // don't trust the hint further than a modest preallocation
+        let mut list = List::with_capacity_and_storage(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(item) = seq.next_element()? {
+            list.push_back(item);
+        }
+        Ok(list)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, S: Storage<Node<T>>> Deserialize<'de> for List<T, S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
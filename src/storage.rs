@@ -0,0 +1,519 @@
+//! Backing storage for [`List`](crate::List).
+//!
+//! A [`Storage`] is a slab whose slots each carry a generation counter, which
+//! the list uses to tell live ids from stale ones. [`SlabStorage`] is the
+//! default, heap-allocated implementation; `InlineStorage` (behind the
+//! `smallvec` feature) keeps a fixed number of slots inline before spilling.
+
+use alloc::vec::Vec;
+
+/// A generational slab.
+///
+/// # Safety
+///
+/// [`List`](crate::List) relies on implementations being correct for memory
+/// safety: `generation` must return `Some` exactly for occupied keys, `insert`
+/// must place the value at the key reported by `vacant`, and distinct keys
+/// must refer to disjoint values.
+pub unsafe trait Storage<T> {
+    /// A pointer to the slots, through which iterators can hold `&mut`s to
+    /// several values at once.
+    type Raw: Copy;
+
+    fn with_capacity(capacity: usize) -> Self;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn capacity(&self) -> usize;
+
+    /// One past the highest key that has ever been occupied.
+    fn slots(&self) -> usize;
+
+    fn reserve(&mut self, additional: usize);
+
+    fn reserve_exact(&mut self, additional: usize);
+
+    fn shrink_to_fit(&mut self);
+
+    /// The key and generation the next inserted value will receive.
+    fn vacant(&self) -> (usize, u32);
+
+    /// The generation of `key`, or `None` if it is vacant.
+    fn generation(&self, key: usize) -> Option<u32>;
+
+    fn insert(&mut self, value: T) -> usize;
+
+    /// Removes the value at an occupied `key`, advancing its generation.
+    fn remove(&mut self, key: usize) -> T;
+
+    /// Removes every value, advancing the generation of each occupied slot.
+    /// Subsequent inserts fill keys in ascending order from 0.
+    fn clear(&mut self);
+
+    fn get(&self, key: usize) -> Option<&T>;
+
+    fn get_mut(&mut self, key: usize) -> Option<&mut T>;
+
+    /// # Safety
+    ///
+    /// `key` must be occupied.
+    unsafe fn get_unchecked(&self, key: usize) -> &T;
+
+    /// # Safety
+    ///
+    /// `key` must be occupied.
+    unsafe fn get_unchecked_mut(&mut self, key: usize) -> &mut T;
+
+    /// Returns `None` if any key is vacant or repeated.
+    fn get_disjoint_mut<const N: usize>(&mut self, keys: [usize; N]) -> Option<[&mut T; N]>;
+
+    /// Returns a pointer to the slots for [`raw_get`](Storage::raw_get).
+    fn raw(&mut self) -> Self::Raw;
+
+    /// Returns a pointer to the value at `key`, computed from `raw` without
+    /// creating a reference to any other slot.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must come from [`raw`](Storage::raw) on a storage that has not
+    /// been used since, other than through `raw_get`, and `key` must be
+    /// occupied.
+    unsafe fn raw_get(raw: Self::Raw, key: usize) -> *mut T;
+}
+
+#[derive(Clone)]
+enum Entry<T> {
+    Occupied(T),
+    Vacant(usize),
+}
+
+/// The default storage: a vector of entries threaded with a list of vacant
+/// ones, plus a side table of generations that outlives the slots, so that
+/// trailing vacant slots can be released without reviving stale ids.
+#[derive(Clone)]
+pub struct SlabStorage<T> {
+    entries: Vec<Entry<T>>,
+    generations: Vec<u32>,
+    len: usize,
+    next: usize,
+}
+
+/// The [`Raw`](Storage::Raw) pointer of a [`SlabStorage`].
+pub struct SlabRaw<T>(*mut Entry<T>);
+
+impl<T> Clone for SlabRaw<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SlabRaw<T> {}
+
+impl<T> Default for SlabStorage<T> {
+    fn default() -> Self {
+        SlabStorage {
+            entries: Vec::new(),
+            generations: Vec::new(),
+            len: 0,
+            next: 0,
+        }
+    }
+}
+
+impl<T> SlabStorage<T> {
+    /// How many more slots are needed to hold `additional` more values.
+    fn missing(&self, additional: usize) -> usize {
+        additional.saturating_sub(self.entries.len() - self.len)
+    }
+
+    fn reserve_generations(&mut self) {
+        let missing = self
+            .entries
+            .capacity()
+            .saturating_sub(self.generations.len());
+        self.generations.reserve_exact(missing);
+    }
+}
+
+unsafe impl<T> Storage<T> for SlabStorage<T> {
+    type Raw = SlabRaw<T>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        SlabStorage {
+            entries: Vec::with_capacity(capacity),
+            generations: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    fn slots(&self) -> usize {
+        self.generations.len()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(self.missing(additional));
+        self.reserve_generations();
+    }
+
+    fn reserve_exact(&mut self, additional: usize) {
+        self.entries.reserve_exact(self.missing(additional));
+        self.reserve_generations();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        let before = self.entries.len();
+        while let Some(Entry::Vacant(_)) = self.entries.last() {
+            self.entries.pop();
+        }
+        if self.entries.len() != before {
+            // the vacant list may point past the end now; rebuild it in
+            // ascending key order
+            self.next = self.entries.len();
+            for (key, entry) in self.entries.iter_mut().enumerate().rev() {
+                if let Entry::Vacant(next) = entry {
+                    *next = self.next;
+                    self.next = key;
+                }
+            }
+        }
+        self.entries.shrink_to_fit();
+        self.generations.shrink_to_fit();
+    }
+
+    fn vacant(&self) -> (usize, u32) {
+        let key = self.next;
+        (key, self.generations.get(key).copied().unwrap_or(0))
+    }
+
+    fn generation(&self, key: usize) -> Option<u32> {
+        match self.entries.get(key) {
+            Some(Entry::Occupied(_)) => Some(self.generations[key]),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, value: T) -> usize {
+        let key = self.next;
+        if key == self.entries.len() {
+            self.entries.push(Entry::Occupied(value));
+            self.next = key + 1;
+        } else {
+            match self.entries[key] {
+                Entry::Vacant(next) => {
+                    self.entries[key] = Entry::Occupied(value);
+                    self.next = next;
+                }
+                Entry::Occupied(_) => unreachable!("vacant list points at an occupied slot"),
+            }
+        }
+        if key == self.generations.len() {
+            self.generations.push(0);
+        }
+        self.len += 1;
+        key
+    }
+
+    fn remove(&mut self, key: usize) -> T {
+        assert!(self.generation(key).is_some(), "invalid key");
+        match core::mem::replace(&mut self.entries[key], Entry::Vacant(self.next)) {
+            Entry::Occupied(value) => {
+                self.generations[key] = self.generations[key].wrapping_add(1);
+                self.next = key;
+                self.len -= 1;
+                value
+            }
+            Entry::Vacant(_) => unreachable!(),
+        }
+    }
+
+    fn clear(&mut self) {
+        for (key, entry) in self.entries.iter().enumerate() {
+            if let Entry::Occupied(_) = entry {
+                self.generations[key] = self.generations[key].wrapping_add(1);
+            }
+        }
+        self.entries.clear();
+        self.len = 0;
+        self.next = 0;
+    }
+
+    fn get(&self, key: usize) -> Option<&T> {
+        match self.entries.get(key) {
+            Some(Entry::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.entries.get_mut(key) {
+            Some(Entry::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    unsafe fn get_unchecked(&self, key: usize) -> &T {
+        match self.entries.get_unchecked(key) {
+            Entry::Occupied(value) => value,
+            Entry::Vacant(_) => core::hint::unreachable_unchecked(),
+        }
+    }
+
+    unsafe fn get_unchecked_mut(&mut self, key: usize) -> &mut T {
+        match self.entries.get_unchecked_mut(key) {
+            Entry::Occupied(value) => value,
+            Entry::Vacant(_) => core::hint::unreachable_unchecked(),
+        }
+    }
+
+    fn get_disjoint_mut<const N: usize>(&mut self, keys: [usize; N]) -> Option<[&mut T; N]> {
+        if !keys.iter().all(|&key| self.generation(key).is_some()) {
+            return None;
+        }
+        let entries = self.entries.get_disjoint_mut(keys).ok()?;
+        Some(entries.map(|entry| match entry {
+            Entry::Occupied(value) => value,
+            Entry::Vacant(_) => unreachable!(),
+        }))
+    }
+
+    fn raw(&mut self) -> SlabRaw<T> {
+        // unlike indexing, `as_mut_ptr` does not reborrow the whole buffer
+        SlabRaw(self.entries.as_mut_ptr())
+    }
+
+    unsafe fn raw_get(raw: SlabRaw<T>, key: usize) -> *mut T {
+        match &mut *raw.0.add(key) {
+            Entry::Occupied(value) => value,
+            Entry::Vacant(_) => core::hint::unreachable_unchecked(),
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+pub use self::inline::{InlineRaw, InlineStorage};
+
+#[cfg(feature = "smallvec")]
+mod inline {
+    use super::{Entry, Storage};
+    use smallvec::SmallVec;
+
+    /// Storage that keeps up to `N` slots inline, spilling to the heap only
+    /// once more are needed. Generations live in a side table, as in
+    /// [`SlabStorage`](super::SlabStorage).
+    #[derive(Clone)]
+    pub struct InlineStorage<T, const N: usize> {
+        entries: SmallVec<[Entry<T>; N]>,
+        generations: SmallVec<[u32; N]>,
+        len: usize,
+        next: usize,
+    }
+
+    /// The [`Raw`](Storage::Raw) pointer of an [`InlineStorage`].
+    pub struct InlineRaw<T>(*mut Entry<T>);
+
+    impl<T> Clone for InlineRaw<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for InlineRaw<T> {}
+
+    impl<T, const N: usize> Default for InlineStorage<T, N> {
+        fn default() -> Self {
+            InlineStorage {
+                entries: SmallVec::new(),
+                generations: SmallVec::new(),
+                len: 0,
+                next: 0,
+            }
+        }
+    }
+
+    impl<T, const N: usize> InlineStorage<T, N> {
+        fn missing(&self, additional: usize) -> usize {
+            additional.saturating_sub(self.entries.len() - self.len)
+        }
+
+        fn reserve_generations(&mut self) {
+            let missing = self
+                .entries
+                .capacity()
+                .saturating_sub(self.generations.len());
+            self.generations.reserve_exact(missing);
+        }
+    }
+
+    unsafe impl<T, const N: usize> Storage<T> for InlineStorage<T, N> {
+        type Raw = InlineRaw<T>;
+
+        fn with_capacity(capacity: usize) -> Self {
+            InlineStorage {
+                entries: SmallVec::with_capacity(capacity),
+                generations: SmallVec::with_capacity(capacity),
+                ..Self::default()
+            }
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn capacity(&self) -> usize {
+            self.entries.capacity()
+        }
+
+        fn slots(&self) -> usize {
+            self.generations.len()
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.entries.reserve(self.missing(additional));
+            self.reserve_generations();
+        }
+
+        fn reserve_exact(&mut self, additional: usize) {
+            self.entries.reserve_exact(self.missing(additional));
+            self.reserve_generations();
+        }
+
+        fn shrink_to_fit(&mut self) {
+            let before = self.entries.len();
+            while let Some(Entry::Vacant(_)) = self.entries.last() {
+                self.entries.pop();
+            }
+            if self.entries.len() != before {
+                self.next = self.entries.len();
+                for (key, entry) in self.entries.iter_mut().enumerate().rev() {
+                    if let Entry::Vacant(next) = entry {
+                        *next = self.next;
+                        self.next = key;
+                    }
+                }
+            }
+            self.entries.shrink_to_fit();
+            self.generations.shrink_to_fit();
+        }
+
+        fn vacant(&self) -> (usize, u32) {
+            let key = self.next;
+            (key, self.generations.get(key).copied().unwrap_or(0))
+        }
+
+        fn generation(&self, key: usize) -> Option<u32> {
+            match self.entries.get(key) {
+                Some(Entry::Occupied(_)) => Some(self.generations[key]),
+                _ => None,
+            }
+        }
+
+        fn insert(&mut self, value: T) -> usize {
+            let key = self.next;
+            if key == self.entries.len() {
+                self.entries.push(Entry::Occupied(value));
+                self.next = key + 1;
+            } else {
+                match self.entries[key] {
+                    Entry::Vacant(next) => {
+                        self.entries[key] = Entry::Occupied(value);
+                        self.next = next;
+                    }
+                    Entry::Occupied(_) => unreachable!("vacant list points at an occupied slot"),
+                }
+            }
+            if key == self.generations.len() {
+                self.generations.push(0);
+            }
+            self.len += 1;
+            key
+        }
+
+        fn remove(&mut self, key: usize) -> T {
+            assert!(self.generation(key).is_some(), "invalid key");
+            match core::mem::replace(&mut self.entries[key], Entry::Vacant(self.next)) {
+                Entry::Occupied(value) => {
+                    self.generations[key] = self.generations[key].wrapping_add(1);
+                    self.next = key;
+                    self.len -= 1;
+                    value
+                }
+                Entry::Vacant(_) => unreachable!(),
+            }
+        }
+
+        fn clear(&mut self) {
+            for (key, entry) in self.entries.iter().enumerate() {
+                if let Entry::Occupied(_) = entry {
+                    self.generations[key] = self.generations[key].wrapping_add(1);
+                }
+            }
+            self.entries.clear();
+            self.len = 0;
+            self.next = 0;
+        }
+
+        fn get(&self, key: usize) -> Option<&T> {
+            match self.entries.get(key) {
+                Some(Entry::Occupied(value)) => Some(value),
+                _ => None,
+            }
+        }
+
+        fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+            match self.entries.get_mut(key) {
+                Some(Entry::Occupied(value)) => Some(value),
+                _ => None,
+            }
+        }
+
+        unsafe fn get_unchecked(&self, key: usize) -> &T {
+            match self.entries.get_unchecked(key) {
+                Entry::Occupied(value) => value,
+                Entry::Vacant(_) => core::hint::unreachable_unchecked(),
+            }
+        }
+
+        unsafe fn get_unchecked_mut(&mut self, key: usize) -> &mut T {
+            match self.entries.get_unchecked_mut(key) {
+                Entry::Occupied(value) => value,
+                Entry::Vacant(_) => core::hint::unreachable_unchecked(),
+            }
+        }
+
+        fn get_disjoint_mut<const M: usize>(&mut self, keys: [usize; M]) -> Option<[&mut T; M]> {
+            if !keys.iter().all(|&key| self.generation(key).is_some()) {
+                return None;
+            }
+            let entries = self.entries.get_disjoint_mut(keys).ok()?;
+            Some(entries.map(|entry| match entry {
+                Entry::Occupied(value) => value,
+                Entry::Vacant(_) => unreachable!(),
+            }))
+        }
+
+        fn raw(&mut self) -> InlineRaw<T> {
+            // taken once per iterator: while inline, the buffer is part of
+            // `self`, so each later call would reborrow it whole
+            InlineRaw(self.entries.as_mut_ptr())
+        }
+
+        unsafe fn raw_get(raw: InlineRaw<T>, key: usize) -> *mut T {
+            match &mut *raw.0.add(key) {
+                Entry::Occupied(value) => value,
+                Entry::Vacant(_) => core::hint::unreachable_unchecked(),
+            }
+        }
+    }
+}
@@ -0,0 +1,795 @@
+use linked_slab::List;
+
+fn items(list: &List<i32>) -> Vec<i32> {
+    list.iter().copied().collect()
+}
+
+/// Checks the items in both directions, the ends, and every link.
+fn assert_list(list: &List<i32>, expected: &[i32]) {
+    assert_eq!(items(list), expected);
+    let mut backward: Vec<i32> = list.iter().rev().copied().collect();
+    backward.reverse();
+    assert_eq!(backward, expected);
+    assert_eq!(list.len(), expected.len());
+    assert_eq!(list.front(), expected.first());
+    assert_eq!(list.back(), expected.last());
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn collect_keeps_order() {
+    let list: List<i32> = (0..5).collect();
+    assert!(list.iter().copied().eq(0..5));
+}
+
+#[test]
+fn iter_mut_borrows_are_disjoint() {
+    let mut list: List<i32> = (0..5).collect();
+    let items_mut: Vec<&mut i32> = list.iter_mut().collect();
+    for x in items_mut {
+        *x *= 2;
+    }
+    assert_eq!(items(&list), [0, 2, 4, 6, 8]);
+
+    let mut iter = list.iter_mut();
+    let (a, b) = (iter.next().unwrap(), iter.next_back().unwrap());
+    core::mem::swap(a, b);
+    assert_eq!(items(&list), [8, 2, 4, 6, 0]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn inline_iter_mut_borrows_are_disjoint() {
+    let mut list: linked_slab::InlineList<i32, 8> = (0..5).collect();
+    let items_mut: Vec<&mut i32> = list.iter_mut().collect();
+    for x in items_mut {
+        *x *= 2;
+    }
+    assert!(list.iter().copied().eq(vec![0, 2, 4, 6, 8]));
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn inline_shrink_releases_trailing_slots() {
+    let mut list: linked_slab::InlineList<i32, 4> = (0..13).collect();
+    let ids: Vec<_> = list.iter_ids().collect();
+    for &id in &ids[6..] {
+        list.remove(id);
+    }
+    list.shrink_to_fit();
+    assert_eq!(list.capacity(), 6);
+    let pushed = list.push_back(6);
+    assert!(!list.contains(ids[6]));
+    assert_eq!(list[pushed], 6);
+    assert_eq!(list[ids[5]], 5);
+
+    list.clear();
+    list.shrink_to(2);
+    assert_eq!(list.capacity(), 4);
+    assert!(ids.iter().all(|&id| !list.contains(id)));
+    list.push_back(0);
+    assert!(!list.contains(ids[0]));
+}
+
+#[test]
+fn split_at_mut_halves_mutate_independently() {
+    let mut list = List::new();
+    let ids: Vec<_> = (0..6).map(|x| list.push_back(x)).collect();
+    {
+        let (mut front, mut back) = list.split_at_mut(ids[2]).unwrap();
+        assert_eq!((front.len(), back.len()), (2, 4));
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for x in front.iter_mut() {
+                    *x += 10;
+                }
+            });
+            scope.spawn(|| {
+                for x in back.iter_mut() {
+                    *x *= -1;
+                }
+            });
+        });
+    }
+    assert_eq!(items(&list), [10, 11, -2, -3, -4, -5]);
+
+    let (front, back) = list.split_at_mut(ids[0]).unwrap();
+    assert!(front.is_empty());
+    assert_eq!(back.len(), 6);
+
+    list.remove(ids[0]);
+    assert!(list.split_at_mut(ids[0]).is_none());
+}
+
+/// Deliberately neither `Clone` nor `Copy`.
+#[derive(Debug, PartialEq)]
+struct Opaque(i32);
+
+#[test]
+fn iter_clones_without_clone_items() {
+    let list: List<Opaque> = (0..3).map(Opaque).collect();
+    let mut iter = list.iter();
+    iter.next();
+    let copy = iter.clone();
+    assert!(iter.eq(copy));
+    assert_eq!(list.iter().clone().next(), Some(&Opaque(0)));
+}
+
+#[test]
+fn iter_meets_in_the_middle() {
+    for len in [1, 4, 5].iter().copied() {
+        let list: List<i32> = (0..len).collect();
+        let expected: Vec<i32> = (0..len).collect();
+        assert_list(&list, &expected);
+
+        let mut iter = list.iter();
+        let mut seen = Vec::new();
+        while let Some(&x) = iter.next() {
+            seen.push(x);
+            match iter.next_back() {
+                Some(&x) => seen.push(x),
+                None => break,
+            }
+        }
+        seen.sort_unstable();
+        assert_eq!(seen, expected);
+        assert_eq!((iter.next(), iter.next_back()), (None, None));
+    }
+}
+
+#[test]
+fn clear_keeps_capacity() {
+    let mut list: List<i32> = (0..10).collect();
+    let capacity = list.capacity();
+    list.clear();
+    assert_eq!(list.len(), 0);
+    assert!(list.is_empty());
+    assert_eq!(list.capacity(), capacity);
+    assert_list(&list, &[]);
+
+    list.extend(0..10);
+    assert_eq!(list.capacity(), capacity);
+    assert_list(&list, &(0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn extend_links_the_seam() {
+    let mut list = List::new();
+    list.extend(vec![1, 2]);
+    assert_list(&list, &[1, 2]);
+    list.extend(&[3, 4]);
+    assert_list(&list, &[1, 2, 3, 4]);
+    let seam = list.nth(1).unwrap();
+    let node = list.get(seam).unwrap();
+    assert_eq!(node.next(), list.nth(2));
+    assert_eq!(list.get(node.next().unwrap()).unwrap().prev(), Some(seam));
+
+    list.extend(Vec::<i32>::new());
+    assert_list(&list, &[1, 2, 3, 4]);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn generational_links_cost() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<linked_slab::NodeId>(), 16);
+    assert_eq!(size_of::<linked_slab::Node<u64>>(), 56);
+}
+
+#[test]
+fn remove_current_every_other() {
+    let mut list: List<i32> = (0..7).collect();
+    let mut cursor = list.cursor_front_mut();
+    loop {
+        cursor.remove_current();
+        if !cursor.try_next() {
+            break;
+        }
+    }
+    assert_list(&list, &[1, 3, 5]);
+
+    let mut list: List<i32> = (0..2).collect();
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.remove_current().unwrap().into_inner(), 1);
+    assert_eq!(cursor.item_mut(), Some(&mut 0));
+    cursor.remove_current();
+    assert!(cursor.position().is_none());
+    assert!(cursor.remove_current().is_none());
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn equal_lists_hash_equal() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(list: &List<i32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        list.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut a = List::new();
+    for x in 0..4 {
+        a.push_back(x);
+    }
+    let mut b = List::new();
+    for x in (0..4).rev() {
+        b.push_front(x);
+    }
+    let removed = b.push_back(9);
+    b.remove(removed);
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+
+    b.pop_back();
+    assert_ne!(hash(&a), hash(&b));
+}
+
+#[test]
+fn cursors_cycle_at_the_ends() {
+    let list: List<i32> = (0..3).collect();
+    let mut cursor = list.cursor_back();
+    assert!(cursor.move_next_cycle());
+    assert_eq!(cursor.item(), Some(&0));
+    assert!(cursor.move_prev_cycle());
+    assert_eq!(cursor.item(), Some(&2));
+
+    let mut single: List<i32> = List::new();
+    let only = single.push_back(7);
+    let mut cursor = single.cursor_front();
+    assert!(cursor.move_next_cycle());
+    assert_eq!(cursor.position(), Some(only));
+    assert!(cursor.move_prev_cycle());
+    assert_eq!(cursor.position(), Some(only));
+
+    let mut cursor = single.cursor_front_mut();
+    assert!(cursor.move_next_cycle());
+    assert!(cursor.move_prev_cycle());
+    assert_eq!(cursor.item_mut(), Some(&mut 7));
+
+    let empty: List<i32> = List::new();
+    let mut cursor = empty.cursor_front();
+    assert!(!cursor.move_next_cycle());
+    assert!(!cursor.move_prev_cycle());
+}
+
+#[test]
+fn get_many_mut_swaps_payloads() {
+    let mut list = List::new();
+    let a = list.push_back(1);
+    let b = list.push_back(2);
+    let c = list.push_back(3);
+    {
+        let [x, y] = list.get_many_mut([a, c]).unwrap();
+        core::mem::swap(&mut **x, &mut **y);
+    }
+    assert_list(&list, &[3, 2, 1]);
+    assert_eq!(list.get(a).unwrap().next(), Some(b));
+
+    assert!(list.get_many_mut([a, b, a]).is_none());
+    list.remove(b);
+    assert!(list.get_many_mut([a, b]).is_none());
+    assert!(list.get_many_mut::<0>([]).is_some());
+}
+
+#[test]
+fn reverse_swaps_directions() {
+    let mut list: List<i32> = (0..5).collect();
+    let (front, back) = (list.front_id(), list.back_id());
+    let backward: Vec<i32> = list.iter().rev().copied().collect();
+    list.reverse();
+    assert_eq!(items(&list), backward);
+    assert_list(&list, &[4, 3, 2, 1, 0]);
+    assert_eq!((list.front_id(), list.back_id()), (back, front));
+
+    let mut empty: List<i32> = List::new();
+    empty.reverse();
+    assert_list(&empty, &[]);
+
+    let mut single = List::new();
+    let only = single.push_back(1);
+    single.reverse();
+    assert_list(&single, &[1]);
+    assert_eq!(
+        (single.front_id(), single.back_id()),
+        (Some(only), Some(only))
+    );
+}
+
+#[test]
+fn find_mutate_then_remove() {
+    let mut list: List<i32> = (1..6).collect();
+    assert!(list.find(|&x| x > 9).is_none());
+
+    **list.find_mut(|&x| x % 2 == 0).unwrap() = 20;
+    assert_list(&list, &[1, 20, 3, 4, 5]);
+
+    let found = list.find(|&x| x == 20).unwrap();
+    assert_eq!(list.nth(1), Some(found));
+    assert_eq!(list.remove(found).unwrap().into_inner(), 20);
+    assert_list(&list, &[1, 3, 4, 5]);
+    assert_eq!(list.find(|&x| x % 2 == 0), list.nth(2));
+}
+
+#[test]
+fn splice_into_the_middle() {
+    let mut list: List<i32> = (0..5).collect();
+    let mut cursor = list.cursor_at_mut(list.nth(2).unwrap());
+    cursor.splice_after((10..13).collect());
+    assert_eq!(cursor.item_mut(), Some(&mut 2));
+    cursor.splice_before((20..23).collect());
+    cursor.splice_after(List::new());
+    assert_list(&list, &[0, 1, 20, 21, 22, 2, 10, 11, 12, 3, 4]);
+
+    let mut list: List<i32> = (0..5).collect();
+    let mut cursor = list.cursor_back_mut();
+    cursor.splice_after((10..13).collect());
+    assert_list(&list, &[0, 1, 2, 3, 4, 10, 11, 12]);
+}
+
+/// A fixed pseudo-random sequence, so failures reproduce.
+fn scrambled(len: usize) -> Vec<i32> {
+    let mut state = 0x2545_f491u32;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state % 100) as i32
+        })
+        .collect()
+}
+
+#[test]
+fn sort_relinks_every_node() {
+    let inputs = vec![
+        (0..20).collect::<Vec<_>>(),
+        (0..20).rev().collect(),
+        scrambled(37),
+        vec![],
+        vec![1],
+    ];
+    for input in inputs {
+        let mut list: List<i32> = input.iter().copied().collect();
+        let ids: Vec<_> = list.iter_ids().collect();
+        list.sort();
+        let mut expected = input.clone();
+        expected.sort_unstable();
+        assert_list(&list, &expected);
+        assert!(ids.iter().all(|&id| list.contains(id)));
+    }
+}
+
+#[test]
+fn sort_by_key_is_stable() {
+    let mut list: List<i32> = scrambled(40).into_iter().collect();
+    let original = items(&list);
+    list.sort_by_key(|x| x % 3);
+    let mut expected = original;
+    expected.sort_by_key(|x| x % 3);
+    assert_list(&list, &expected);
+}
+
+#[test]
+fn sort_then_dedup() {
+    let mut list: List<i32> = vec![3, 1, 3, 2, 1, 3].into_iter().collect();
+    list.sort();
+    list.dedup();
+    assert_list(&list, &[1, 2, 3]);
+
+    let mut same: List<i32> = vec![5; 6].into_iter().collect();
+    let front = same.front_id();
+    same.dedup();
+    assert_list(&same, &[5]);
+    assert_eq!(same.front_id(), front);
+
+    let mut list: List<i32> = vec![1, 2, 4, 5, 7].into_iter().collect();
+    list.dedup_by(|a, b| *a - *b == 1);
+    assert_list(&list, &[1, 4, 7]);
+}
+
+#[test]
+fn push_within_capacity_never_grows() {
+    let mut list = List::with_capacity(4);
+    let capacity = list.capacity();
+    for x in 0..capacity as i32 {
+        assert!(list.push_back_within_capacity(x).is_ok());
+    }
+    assert_eq!(list.push_back_within_capacity(-1), Err(-1));
+    assert_eq!(list.push_front_within_capacity(-2), Err(-2));
+    assert_eq!(list.capacity(), capacity);
+    assert_eq!(list.len(), capacity);
+
+    // a freed slot is reused without growing
+    list.pop_front();
+    assert!(list.push_front_within_capacity(-3).is_ok());
+    assert_eq!(list.capacity(), capacity);
+    assert_eq!(list.front(), Some(&-3));
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn ids_clone_without_clone_items() {
+    let list: List<Opaque> = (0..3).map(Opaque).collect();
+    let mut ids = list.iter_ids();
+    ids.next_back();
+    let copy = ids.clone();
+    assert_eq!(copy.len(), 2);
+    assert!(ids.eq(copy));
+}
+
+#[test]
+fn remove_all_scattered_and_adjacent() {
+    let mut list: List<i32> = (0..10).collect();
+    let ids: Vec<_> = list.iter_ids().collect();
+    let scattered = [ids[7], ids[0], ids[4], ids[9], ids[4]];
+    assert_eq!(list.remove_all(&scattered), 4);
+    assert_list(&list, &[1, 2, 3, 5, 6, 8]);
+
+    let adjacent = [ids[3], ids[1], ids[2]];
+    assert_eq!(list.remove_all(&adjacent), 3);
+    assert_list(&list, &[5, 6, 8]);
+
+    assert_eq!(list.remove_all(&scattered), 0);
+    assert_eq!(list.remove_all(&[]), 0);
+    assert_list(&list, &[5, 6, 8]);
+}
+
+#[test]
+fn retain_mut_updates_and_filters() {
+    let mut list: List<i32> = vec![-2, 3, -1, 0, 5, -7].into_iter().collect();
+    list.retain_mut(|x| {
+        *x += 1;
+        *x > 0
+    });
+    assert_list(&list, &[4, 1, 6]);
+
+    list.retain_mut(|_| false);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn chunk_ids_clone_without_clone_items() {
+    let list: List<Opaque> = (0..5).map(Opaque).collect();
+    let mut chunks = list.chunk_ids(2);
+    chunks.next();
+    let copy = chunks.clone();
+    assert_eq!(copy.map(|chunk| chunk.len()).collect::<Vec<_>>(), [2, 1]);
+    assert_eq!(chunks.count(), 2);
+}
+
+#[test]
+fn node_links_end_in_none() {
+    let mut list = List::new();
+    let a = list.push_back(1);
+    let b = list.push_back(2);
+    let (front, back) = (list.get(a).unwrap(), list.get(b).unwrap());
+    assert_eq!((front.prev(), front.next()), (None, Some(b)));
+    assert_eq!((back.prev(), back.next()), (Some(a), None));
+    assert_eq!((list.front_id(), list.back_id()), (Some(a), Some(b)));
+    #[allow(deprecated)]
+    let aliases = (list.init(), list.last());
+    assert_eq!(aliases, (Some(a), Some(b)));
+
+    list.remove(b);
+    let only = list.get(a).unwrap();
+    assert_eq!((only.prev(), only.next()), (None, None));
+}
+
+#[test]
+fn drain_while_stops_at_the_first_miss() {
+    let mut list: List<i32> = (0..6).collect();
+    let drained: Vec<i32> = list.drain_front_while(|&x| x < 2).collect();
+    assert_eq!(drained, [0, 1]);
+    assert_list(&list, &[2, 3, 4, 5]);
+    let drained: Vec<i32> = list.drain_back_while(|&x| x > 3).collect();
+    assert_eq!(drained, [5, 4]);
+    assert_list(&list, &[2, 3]);
+
+    assert_eq!(list.drain_front_while(|&x| x > 9).count(), 0);
+    assert_eq!(list.drain_back_while(|&x| x > 9).count(), 0);
+    assert_list(&list, &[2, 3]);
+
+    // only what is consumed is removed
+    assert_eq!(list.drain_front_while(|_| true).next(), Some(2));
+    assert_list(&list, &[3]);
+
+    assert_eq!(list.drain_back_while(|_| true).count(), 1);
+    assert_list(&list, &[]);
+    assert_eq!(list.drain_front_while(|_| true).next(), None);
+}
+
+#[test]
+fn extract_if_removes_only_what_is_consumed() {
+    let mut list: List<i32> = (0..6).collect();
+    assert_eq!(list.extract_if(|x| *x % 2 == 0).next(), Some(0));
+    assert_list(&list, &[1, 2, 3, 4, 5]);
+    let extracted: Vec<i32> = list.extract_if(|x| *x % 2 == 0).collect();
+    assert_eq!(extracted, [2, 4]);
+    assert_list(&list, &[1, 3, 5]);
+    assert_eq!(list.extract_if(|_| false).count(), 0);
+    assert_eq!(list.extract_if(|_| true).count(), 3);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn clone_at_checks_the_id() {
+    let mut list: List<i32> = (0..3).collect();
+    let stale = list.push_back(3);
+    list.remove(stale);
+    let last = list.back_id().unwrap();
+
+    let cursor = list.cursor_front();
+    let at_last = cursor.clone_at(last);
+    assert_eq!(at_last.position(), Some(last));
+    assert_eq!(cursor.position(), list.front_id());
+
+    let at_stale = cursor.clone_at(stale);
+    assert_eq!(at_stale.position(), None);
+    assert!(at_stale.is_dangling());
+}
+
+#[test]
+fn window_ids_clone_without_clone_items() {
+    let list: List<Opaque> = (0..4).map(Opaque).collect();
+    let mut windows = list.window_ids(2);
+    windows.next();
+    let copy = windows.clone();
+    assert!(windows.eq(copy));
+    assert_eq!(list.window_ids(2).clone().count(), 3);
+}
+
+#[test]
+fn partition_keeps_relative_order() {
+    let mut list: List<i32> = vec![5, 2, 8, 1, 4, 7, 6].into_iter().collect();
+    let odd = list.partition(|x| x % 2 == 0);
+    assert_list(&list, &[2, 8, 4, 6]);
+    assert_list(&odd, &[5, 1, 7]);
+
+    let none = list.partition(|_| true);
+    assert_list(&none, &[]);
+    let all = list.partition(|_| false);
+    assert_list(&list, &[]);
+    assert_list(&all, &[2, 8, 4, 6]);
+}
+
+#[test]
+fn lists_compare_lexicographically() {
+    use core::cmp::Ordering;
+
+    let list = |items: &[i32]| items.iter().copied().collect::<List<i32>>();
+    assert_eq!(list(&[1, 2]).cmp(&list(&[1, 2, 3])), Ordering::Less);
+    assert_eq!(list(&[1, 2, 3]).cmp(&list(&[1, 2])), Ordering::Greater);
+    assert_eq!(list(&[]).cmp(&list(&[0])), Ordering::Less);
+    assert_eq!(list(&[2]).cmp(&list(&[1, 9, 9])), Ordering::Greater);
+    assert_eq!(list(&[1, 2]).cmp(&list(&[1, 2])), Ordering::Equal);
+
+    let floats: List<f64> = vec![1.0, f64::NAN].into_iter().collect();
+    assert_eq!(floats.partial_cmp(&floats), None);
+}
+
+#[test]
+fn get2_mut_needs_two_live_ids() {
+    let mut list = List::new();
+    let a = list.push_back(1);
+    let b = list.push_back(2);
+    {
+        let (x, y) = list.get2_mut(a, b).unwrap();
+        **x += 10;
+        **y += 20;
+    }
+    assert_list(&list, &[11, 22]);
+
+    assert!(list.get2_mut(a, a).is_none());
+    list.remove(b);
+    assert!(list.get2_mut(a, b).is_none());
+    assert!(list.get2_mut(b, a).is_none());
+}
+
+#[test]
+fn retain_ids_in_a_set() {
+    use std::collections::HashSet;
+
+    let mut list: List<i32> = (0..8).collect();
+    let ids: Vec<_> = list.iter_ids().collect();
+    let keep: HashSet<_> = [ids[1], ids[2], ids[6]].iter().copied().collect();
+    list.retain_ids(|id| keep.contains(&id));
+    assert_list(&list, &[1, 2, 6]);
+    assert!(list.iter_ids().all(|id| keep.contains(&id)));
+
+    list.retain_ids(|_| false);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn pop_if_checks_the_end() {
+    let mut list: List<i32> = (1..5).collect();
+    assert!(list.pop_front_if(|&x| x > 1).is_none());
+    assert_eq!(list.pop_front_if(|&x| x == 1).unwrap().into_inner(), 1);
+    assert!(list.pop_back_if(|&x| x < 4).is_none());
+    assert_eq!(list.pop_back_if(|&x| x == 4).unwrap().into_inner(), 4);
+    assert_list(&list, &[2, 3]);
+
+    let mut empty: List<i32> = List::new();
+    assert!(empty.pop_front_if(|_| true).is_none());
+    assert!(empty.pop_back_if(|_| true).is_none());
+}
+
+#[test]
+fn remove_get_neighbors_reports_old_links() {
+    let mut list: List<i32> = (0..4).collect();
+    let ids: Vec<_> = list.iter_ids().collect();
+
+    let (prev, node, next) = list.remove_get_neighbors(ids[1]).unwrap();
+    assert_eq!(
+        (prev, node.into_inner(), next),
+        (Some(ids[0]), 1, Some(ids[2]))
+    );
+    assert_eq!(list.get(ids[0]).unwrap().next(), Some(ids[2]));
+
+    let (prev, _, next) = list.remove_get_neighbors(ids[0]).unwrap();
+    assert_eq!((prev, next), (None, Some(ids[2])));
+    let (prev, _, next) = list.remove_get_neighbors(ids[3]).unwrap();
+    assert_eq!((prev, next), (Some(ids[2]), None));
+    assert_list(&list, &[2]);
+
+    assert!(list.remove_get_neighbors(ids[1]).is_none());
+}
+
+#[test]
+fn merge_sorted_interleaves() {
+    let sorted = |items: &[i32]| items.iter().copied().collect::<List<i32>>();
+
+    let mut list = sorted(&[1, 4, 6, 9]);
+    list.merge_sorted(sorted(&[2, 3, 6, 10]));
+    assert_list(&list, &[1, 2, 3, 4, 6, 6, 9, 10]);
+
+    let mut list = sorted(&[5, 6]);
+    list.merge_sorted(sorted(&[1, 2]));
+    assert_list(&list, &[1, 2, 5, 6]);
+    list.merge_sorted(sorted(&[7, 8]));
+    assert_list(&list, &[1, 2, 5, 6, 7, 8]);
+
+    list.merge_sorted(List::new());
+    assert_list(&list, &[1, 2, 5, 6, 7, 8]);
+    let mut empty = List::new();
+    empty.merge_sorted(sorted(&[3, 4]));
+    assert_list(&empty, &[3, 4]);
+
+    let mut list = sorted(&[9, 5, 1]);
+    list.merge_sorted_by(sorted(&[8, 2]), |a, b| b.cmp(a));
+    assert_list(&list, &[9, 8, 5, 2, 1]);
+}
+
+#[test]
+fn resize_grows_and_shrinks() {
+    let mut list = List::new();
+    list.resize(3, 7);
+    assert_list(&list, &[7, 7, 7]);
+
+    let mut next = 0;
+    list.resize_with(5, || {
+        next += 1;
+        next
+    });
+    assert_list(&list, &[7, 7, 7, 1, 2]);
+
+    list.resize(2, 0);
+    assert_list(&list, &[7, 7]);
+    list.resize_with(2, || unreachable!());
+    assert_list(&list, &[7, 7]);
+    list.resize(0, 0);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn split_off_ends_clamp_the_count() {
+    let mut list: List<i32> = (0..6).collect();
+    let back = list.split_off_back(2);
+    assert_list(&list, &[0, 1, 2, 3]);
+    assert_list(&back, &[4, 5]);
+    let front = list.split_off_front(1);
+    assert_list(&list, &[1, 2, 3]);
+    assert_list(&front, &[0]);
+
+    assert_list(&list.split_off_back(0), &[]);
+    assert_list(&list.split_off_front(0), &[]);
+    assert_list(&list, &[1, 2, 3]);
+
+    let mut copy = list.clone();
+    assert_list(&copy.split_off_front(10), &[1, 2, 3]);
+    assert_list(&copy, &[]);
+    assert_list(&list.split_off_back(3), &[1, 2, 3]);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn reverse_range_relinks_a_segment() {
+    let mut list = List::new();
+    let ids: Vec<_> = (0..6).map(|x| list.push_back(x)).collect();
+    list.reverse_range(ids[1], ids[4]).unwrap();
+    assert_list(&list, &[0, 4, 3, 2, 1, 5]);
+    assert_eq!(list[ids[4]], 4);
+
+    list.reverse_range(ids[0], ids[5]).unwrap();
+    assert_list(&list, &[5, 1, 2, 3, 4, 0]);
+    list.reverse_range(ids[2], ids[2]).unwrap();
+    assert_list(&list, &[5, 1, 2, 3, 4, 0]);
+
+    assert_eq!(
+        list.reverse_range(ids[0], ids[5]),
+        Err(linked_slab::ListError::InvalidRange)
+    );
+    assert_list(&list, &[5, 1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn zip_stops_at_the_shorter_list() {
+    let numbers: List<i32> = (0..5).collect();
+    let words: List<&str> = ["a", "b", "c"].iter().copied().collect();
+    let pairs: Vec<_> = numbers.zip(&words).map(|(&n, &w)| (n, w)).collect();
+    assert_eq!(pairs, [(0, "a"), (1, "b"), (2, "c")]);
+    assert_eq!(words.zip(&numbers).count(), 3);
+    assert_eq!(numbers.zip(&List::<u8>::new()).count(), 0);
+}
+
+#[test]
+fn shrink_to_fit_keeps_live_ids() {
+    let mut list = List::new();
+    let ids: Vec<_> = (0..10).map(|x| list.push_back(x)).collect();
+    let gone = [ids[3], ids[8], ids[9]];
+    for &id in &gone {
+        list.remove(id);
+    }
+    list.shrink_to_fit();
+    assert_eq!(list.capacity(), 8);
+    assert_list(&list, &[0, 1, 2, 4, 5, 6, 7]);
+    for (x, &id) in ids.iter().enumerate() {
+        assert_eq!(
+            list.get(id).map(|node| **node),
+            Some(x as i32).filter(|_| !gone.contains(&id))
+        );
+    }
+
+    let pushed: Vec<_> = (10..13).map(|x| list.push_back(x)).collect();
+    assert_list(&list, &[0, 1, 2, 4, 5, 6, 7, 10, 11, 12]);
+    assert!(gone.iter().all(|&id| !list.contains(id)));
+    assert!(pushed.iter().all(|&id| list.contains(id)));
+    assert_eq!(list[ids[7]], 7);
+}
+
+#[test]
+fn clear_invalidates_every_id() {
+    let mut list = List::new();
+    let ids: Vec<_> = (0..5).map(|x| list.push_back(x)).collect();
+    list.remove(ids[2]);
+    list.clear();
+    let fresh: Vec<_> = (5..10).map(|x| list.push_back(x)).collect();
+    assert_list(&list, &[5, 6, 7, 8, 9]);
+    assert!(ids.iter().all(|&id| !list.contains(id)));
+    for (x, &id) in (5..).zip(&fresh) {
+        assert_eq!(list[id], x);
+    }
+    assert!(list.is_contiguous());
+}
+
+#[test]
+fn compact_maps_old_ids_to_new() {
+    let mut list = List::new();
+    let ids: Vec<_> = (0..8).map(|x| list.push_front(x)).collect();
+    list.remove(ids[1]);
+    list.remove(ids[6]);
+    let before: Vec<_> = list.iter_ids().map(|id| (id, list[id])).collect();
+
+    let mapping = list.compact();
+    assert!(list.is_contiguous());
+    assert_list(&list, &[7, 5, 4, 3, 2, 0]);
+    assert_eq!(mapping.len(), before.len());
+    for (&(old, new), &(id, x)) in mapping.iter().zip(&before) {
+        assert_eq!(old, id);
+        assert!(!list.contains(old));
+        assert_eq!(list[new], x);
+    }
+    assert!(list.iter_ids().eq(mapping.iter().map(|&(_, new)| new)));
+}
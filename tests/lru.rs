@@ -0,0 +1,48 @@
+use linked_slab::lru::LruCache;
+
+#[test]
+fn get_makes_an_entry_most_recent() {
+    let mut cache = LruCache::new(2);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    assert_eq!(cache.get(&"a"), Some(&1));
+    cache.put("c", 3);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&"b"), None);
+    assert_eq!(cache.pop_lru(), Some(("a", 1)));
+    assert_eq!(cache.pop_lru(), Some(("c", 3)));
+    assert_eq!(cache.pop_lru(), None);
+}
+
+#[test]
+fn put_into_a_full_cache_evicts_the_oldest() {
+    let mut cache = LruCache::new(3);
+    for (key, value) in ["a", "b", "c", "d"].iter().zip(1..) {
+        assert_eq!(cache.put(*key, value), None);
+    }
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.get(&"a"), None);
+    assert_eq!(cache.get(&"b"), Some(&2));
+    assert_eq!(cache.get(&"d"), Some(&4));
+}
+
+#[test]
+fn overwriting_a_key_keeps_every_entry() {
+    let mut cache = LruCache::new(2);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    assert_eq!(cache.put("a", 10), Some(1));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&"b"), Some(&2));
+    assert_eq!(cache.get(&"a"), Some(&10));
+    assert_eq!(cache.pop_lru(), Some(("b", 2)));
+}
+
+#[test]
+fn zero_capacity_stores_nothing() {
+    let mut cache = LruCache::new(0);
+    assert_eq!(cache.put("a", 1), None);
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(&"a"), None);
+    assert_eq!(cache.pop_lru(), None);
+}
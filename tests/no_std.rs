@@ -0,0 +1,27 @@
+//! Exercises the crate without its `std` feature:
+//!
+//! ```text
+//! cargo test --no-default-features --test no_std
+//! ```
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use linked_slab::List;
+
+#[test]
+fn push_and_iterate() {
+    let mut list = List::new();
+    let middle = list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list[middle], 2);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+    for x in list.iter_mut() {
+        *x *= 10;
+    }
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+}
@@ -0,0 +1,14 @@
+use linked_slab::List;
+use rayon::prelude::*;
+
+#[test]
+fn par_sum_matches_sequential() {
+    let mut list: List<u64> = (0..100_000).collect();
+    for id in list.iter_ids().step_by(3).collect::<Vec<_>>() {
+        list.remove(id);
+    }
+    let sequential: u64 = list.iter().sum();
+    let parallel: u64 = (&list).into_par_iter().sum();
+    assert_eq!(parallel, sequential);
+    assert_eq!(list.par_iter().count(), list.len());
+}
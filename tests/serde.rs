@@ -0,0 +1,19 @@
+use linked_slab::List;
+
+#[test]
+fn round_trips_as_a_sequence() {
+    let mut list = List::new();
+    for x in 1..5 {
+        list.push_back(x);
+    }
+    list.push_front(0);
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, "[0,1,2,3,4]");
+
+    let back: List<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, list);
+    assert!(back.iter().rev().copied().eq((0..5).rev()));
+
+    let empty: List<i32> = serde_json::from_str("[]").unwrap();
+    assert!(empty.is_empty());
+}
@@ -0,0 +1,98 @@
+use linked_slab::singly::SinglyList;
+
+fn items(list: &SinglyList<i32>) -> Vec<i32> {
+    list.iter().copied().collect()
+}
+
+/// Checks the items, the ends and the length.
+fn assert_list(list: &SinglyList<i32>, expected: &[i32]) {
+    assert_eq!(items(list), expected);
+    assert_eq!(list.len(), expected.len());
+    assert_eq!(list.is_empty(), expected.is_empty());
+    assert_eq!(list.front(), expected.first());
+    assert_eq!(list.back(), expected.last());
+}
+
+#[test]
+fn iter_mut_borrows_are_disjoint() {
+    let mut list: SinglyList<i32> = (0..5).collect();
+    let items_mut: Vec<&mut i32> = list.iter_mut().collect();
+    for x in items_mut {
+        *x *= 2;
+    }
+    assert!(list.iter().copied().eq(vec![0, 2, 4, 6, 8]));
+}
+
+#[test]
+fn shares_list_traits() {
+    let mut list: SinglyList<i32> = (1..4).collect();
+    list.extend([4, 5].iter());
+    let front = list.front_id().unwrap();
+    list[front] = 10;
+    assert_eq!(list[front], 10);
+    assert_eq!(format!("{:?}", list), "[10, 2, 3, 4, 5]");
+    assert_eq!(list.iter().len(), 5);
+
+    let mut into_iter = list.clone().into_iter();
+    assert_eq!(into_iter.len(), 5);
+    assert_eq!(into_iter.next(), Some(10));
+    assert_eq!(into_iter.collect::<Vec<_>>(), [2, 3, 4, 5]);
+    assert_eq!((&list).into_iter().count(), 5);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn nodes_drop_one_link() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<linked_slab::singly::Node<u64>>(), 32);
+    assert_eq!(size_of::<linked_slab::Node<u64>>(), 56);
+}
+
+#[test]
+fn pop_front_empties_and_refills() {
+    let mut list = SinglyList::new();
+    list.push_back(2);
+    list.push_front(1);
+    assert_list(&list, &[1, 2]);
+    assert_eq!(list.pop_front().map(|node| node.into_inner()), Some(1));
+    assert_eq!(list.pop_front().map(|node| node.into_inner()), Some(2));
+    assert!(list.pop_front().is_none());
+    assert_list(&list, &[]);
+    assert_eq!((list.front_id(), list.back_id()), (None, None));
+
+    list.push_front(4);
+    list.push_back(5);
+    list.push_front(3);
+    assert_list(&list, &[3, 4, 5]);
+}
+
+#[test]
+fn links_after_the_back_move_it() {
+    let mut list: SinglyList<i32> = (0..3).collect();
+    let back = list.back_id().unwrap();
+    let new_back = list.insert_after(back, 3).unwrap();
+    assert_eq!(list.back_id(), Some(new_back));
+    assert_list(&list, &[0, 1, 2, 3]);
+    list.push_back(4);
+    assert_list(&list, &[0, 1, 2, 3, 4]);
+
+    let last = list.back_id().unwrap();
+    assert!(list.remove_after(last).is_none());
+    assert_eq!(
+        list.remove_after(new_back).map(|node| node.into_inner()),
+        Some(4)
+    );
+    assert_eq!(list.back_id(), Some(new_back));
+    assert_list(&list, &[0, 1, 2, 3]);
+    assert!(list.remove_after(new_back).is_none());
+    list.push_back(5);
+    assert_list(&list, &[0, 1, 2, 3, 5]);
+
+    assert_eq!(
+        list.remove_after(back).map(|node| node.into_inner()),
+        Some(3)
+    );
+    assert!(!list.contains(new_back));
+    assert!(list.insert_after(new_back, 9).is_none());
+    assert_list(&list, &[0, 1, 2, 5]);
+}